@@ -0,0 +1,285 @@
+//! Procedural macros for the [`meilisearch-sdk`](https://crates.io/crates/meilisearch-sdk) crate.
+//!
+//! Don't depend on this crate directly: enable the `derive` feature of `meilisearch-sdk` instead.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_macro_input, Attribute, Data, DeriveInput, Error, Lit, Meta, MetaList, NestedMeta};
+
+/// Derive `meilisearch_sdk::fields::Fields`, generating a `<Name>Fields` struct holding the name of each attribute of the document.
+///
+/// Attribute names are the ones used by serde when serializing the document:
+/// `#[serde(rename = "...")]` and `#[serde(rename_all = "...")]` are respected,
+/// and fields marked with `#[serde(skip)]`, `#[serde(skip_serializing)]` or `#[serde(flatten)]` are left out.
+#[proc_macro_derive(Fields, attributes(serde))]
+pub fn derive_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_fields(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_fields(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "`Fields` can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "`Fields` can only be derived for structs")),
+    };
+
+    let container = SerdeAttributes::parse(&input.attrs)?;
+    let mut idents = Vec::new();
+    let mut names = Vec::new();
+    for field in fields {
+        if let Some(name) = serde_name(input, &container, field)? {
+            idents.push(field.ident.as_ref().unwrap());
+            names.push(name);
+        }
+    }
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let fields_name = format_ident!("{}Fields", name);
+    let struct_doc = format!("Names of the attributes of a [`{}`] document.", name);
+    let field_docs = names.iter().map(|name| format!("The `{}` attribute.", name));
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #fields_name {
+            #(
+                #[doc = #field_docs]
+                pub #idents: ::meilisearch_sdk::fields::Field,
+            )*
+        }
+
+        impl #impl_generics ::meilisearch_sdk::fields::Fields for #name #ty_generics #where_clause {
+            type Fields = #fields_name;
+
+            fn fields() -> #fields_name {
+                #fields_name {
+                    #( #idents: ::meilisearch_sdk::fields::Field::new(#names), )*
+                }
+            }
+        }
+    })
+}
+
+/// Derive `meilisearch_sdk::document::Document` and `meilisearch_sdk::document::IndexedDocument`.
+///
+/// The index and the primary key are set with `#[document(index = "movies", primary_key = "id")]`, where `primary_key` is the name of the Rust field.
+/// It defaults to the `id` field. The attribute name of the primary key is the one used by serde.
+#[proc_macro_derive(Document, attributes(document, serde))]
+pub fn derive_document(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_document(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_document(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "`Document` can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "`Document` can only be derived for structs")),
+    };
+
+    let mut index = None;
+    let mut primary_key = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("document")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "expected `#[document(index = \"...\", primary_key = \"...\")]`")),
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("index") => index = Some(lit_str(&meta.lit)?),
+                NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("primary_key") => {
+                    primary_key = Some((lit_str(&meta.lit)?, meta.lit))
+                }
+                nested => return Err(Error::new_spanned(nested, "unknown document attribute, expected `index` or `primary_key`")),
+            }
+        }
+    }
+    let index = index.ok_or_else(|| {
+        Error::new_spanned(&input.ident, "missing `#[document(index = \"...\")]` attribute")
+    })?;
+
+    let field = match &primary_key {
+        Some((primary_key, lit)) => fields
+            .iter()
+            .find(|field| field.ident.as_ref().unwrap().unraw() == primary_key)
+            .ok_or_else(|| Error::new_spanned(lit, format!("no field named `{}`", primary_key)))?,
+        None => fields
+            .iter()
+            .find(|field| field.ident.as_ref().unwrap().unraw() == "id")
+            .ok_or_else(|| Error::new_spanned(&input.ident, "no `id` field, set the primary key with `#[document(primary_key = \"...\")]`"))?,
+    };
+    let container = SerdeAttributes::parse(&input.attrs)?;
+    let primary_key_name = serde_name(input, &container, field)?
+        .ok_or_else(|| Error::new_spanned(field, "the primary key can't be skipped or flattened"))?;
+
+    let name = &input.ident;
+    let ident = field.ident.as_ref().unwrap();
+    let ty = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::meilisearch_sdk::document::Document for #name #ty_generics #where_clause {
+            type UIDType = #ty;
+
+            fn get_uid(&self) -> &Self::UIDType {
+                &self.#ident
+            }
+        }
+
+        impl #impl_generics ::meilisearch_sdk::document::IndexedDocument for #name #ty_generics #where_clause {
+            const INDEX_UID: &'static str = #index;
+            const PRIMARY_KEY: &'static str = #primary_key_name;
+        }
+    })
+}
+
+/// Get the name serde gives to a field, or `None` if the field is skipped or flattened.
+fn serde_name(input: &DeriveInput, container: &SerdeAttributes, field: &syn::Field) -> syn::Result<Option<String>> {
+    let attributes = SerdeAttributes::parse(&field.attrs)?;
+    if attributes.skip || attributes.flatten {
+        return Ok(None);
+    }
+    let ident = field.ident.as_ref().unwrap();
+    let name = match attributes.rename {
+        Some(name) => name,
+        None => match &container.rename_all {
+            Some(rule) => rename(&ident.unraw().to_string(), rule).ok_or_else(|| {
+                Error::new_spanned(&input.ident, format!("unknown rename rule `{}`", rule))
+            })?,
+            None => ident.unraw().to_string(),
+        },
+    };
+    Ok(Some(name))
+}
+
+/// The serde attributes affecting the name of a field.
+#[derive(Default)]
+struct SerdeAttributes {
+    rename: Option<String>,
+    rename_all: Option<String>,
+    skip: bool,
+    flatten: bool,
+}
+
+impl SerdeAttributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<SerdeAttributes> {
+        let mut parsed = SerdeAttributes::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                _ => continue,
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("rename") => {
+                        parsed.rename = Some(lit_str(&meta.lit)?)
+                    }
+                    NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("rename_all") => {
+                        parsed.rename_all = Some(lit_str(&meta.lit)?)
+                    }
+                    NestedMeta::Meta(Meta::List(meta)) if meta.path.is_ident("rename") => {
+                        parsed.rename = serialize_name(&meta)?.or(parsed.rename)
+                    }
+                    NestedMeta::Meta(Meta::List(meta)) if meta.path.is_ident("rename_all") => {
+                        parsed.rename_all = serialize_name(&meta)?.or(parsed.rename_all)
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") || path.is_ident("skip_serializing") => {
+                        parsed.skip = true
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("flatten") => parsed.flatten = true,
+                    _ => (),
+                }
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn lit_str(lit: &Lit) -> syn::Result<String> {
+    match lit {
+        Lit::Str(lit) => Ok(lit.value()),
+        _ => Err(Error::new_spanned(lit, "expected a string literal")),
+    }
+}
+
+/// Get the `serialize` name of `rename(serialize = "...", deserialize = "...")`.
+fn serialize_name(list: &MetaList) -> syn::Result<Option<String>> {
+    for nested in &list.nested {
+        if let NestedMeta::Meta(Meta::NameValue(meta)) = nested {
+            if meta.path.is_ident("serialize") {
+                return lit_str(&meta.lit).map(Some);
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Apply a serde `rename_all` rule to a snake_case field name.
+fn rename(field: &str, rule: &str) -> Option<String> {
+    let pascal_case = || {
+        let mut renamed = String::with_capacity(field.len());
+        let mut capitalize = true;
+        for c in field.chars() {
+            if c == '_' {
+                capitalize = true;
+            } else if capitalize {
+                renamed.push(c.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                renamed.push(c);
+            }
+        }
+        renamed
+    };
+
+    match rule {
+        "lowercase" | "snake_case" => Some(field.to_ascii_lowercase()),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => Some(field.to_ascii_uppercase()),
+        "PascalCase" => Some(pascal_case()),
+        "camelCase" => {
+            let pascal = pascal_case();
+            let mut chars = pascal.chars();
+            Some(match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => pascal,
+            })
+        }
+        "kebab-case" => Some(field.replace('_', "-")),
+        "SCREAMING-KEBAB-CASE" => Some(field.to_ascii_uppercase().replace('_', "-")),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rename;
+
+    #[test]
+    fn test_rename() {
+        assert_eq!(rename("release_date", "lowercase").unwrap(), "release_date");
+        assert_eq!(rename("release_date", "UPPERCASE").unwrap(), "RELEASE_DATE");
+        assert_eq!(rename("release_date", "PascalCase").unwrap(), "ReleaseDate");
+        assert_eq!(rename("release_date", "camelCase").unwrap(), "releaseDate");
+        assert_eq!(rename("release_date", "snake_case").unwrap(), "release_date");
+        assert_eq!(rename("release_date", "SCREAMING_SNAKE_CASE").unwrap(), "RELEASE_DATE");
+        assert_eq!(rename("release_date", "kebab-case").unwrap(), "release-date");
+        assert_eq!(rename("release_date", "SCREAMING-KEBAB-CASE").unwrap(), "RELEASE-DATE");
+        assert!(rename("release_date", "Title Case").is_none());
+    }
+}
@@ -1,18 +1,439 @@
-use crate::{errors::*, indexes::*, request::*, Rc};
+use crate::{document::{DocumentIndex, IndexedDocument}, errors::*, indexes::*, request::*, search::*, tasks::TaskInfo, Rc};
+use futures::{stream, StreamExt};
+use log::info;
 use serde_json::{json, Value};
-use serde::{Deserialize};
-use std::collections::HashMap;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::HashMap, time::Duration};
 
 /// The top-level struct of the SDK, representing a client containing [indexes](../indexes/struct.Index.html).
 #[derive(Debug)]
 pub struct Client {
     pub(crate) host: Rc<String>,
-    pub(crate) api_key: Rc<String>,
+    pub(crate) config: Rc<RequestConfig>,
+    pub(crate) compression: Option<Compression>,
+}
+
+/// An algorithm compressing the documents sent to MeiliSearch, enabled with [`Client::with_compression`] or [`Index::with_compression`](../indexes/struct.Index.html#method.with_compression).
+///
+/// Compression requires the `compression` feature.
+/// With this feature, responses are also requested compressed with gzip, deflate or brotli, and decompressed transparently
+/// (browsers always do it by themselves).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// `Content-Encoding: gzip`
+    Gzip,
+    /// `Content-Encoding: deflate`, a zlib stream
+    Deflate,
+    /// `Content-Encoding: br`
+    Brotli,
+}
+
+/// How the requests failing because of a transient error are retried, set with [`Client::with_retry_policy`].
+///
+/// A request is retried when it couldn't reach the server, or, if it is idempotent (`GET`, `PUT` and `DELETE` requests), when it timed out,
+/// failed because of a network error, or received a `502`, `503` or `504` response, which MeiliSearch and proxies return when they are temporarily unavailable.
+/// Requests sending a stream, such as [`Index::add_documents_from_reader`](../indexes/struct.Index.html#method.add_documents_from_reader), are never retried.
+///
+/// The delay before the `n`th retry is `base_delay * 2^(n-1)`, up to `max_delay`.
+/// With `jitter`, a random delay between half and all of it is used instead, so that many clients don't retry at the same time.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::client::*;
+/// # use std::time::Duration;
+/// let retry_policy = RetryPolicy::new(5).with_base_delay(Duration::from_millis(200));
+/// let client = Client::new("http://localhost:7700", "masterKey").with_retry_policy(Some(retry_policy));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is sent, including the first one
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+}
+
+#[allow(missing_docs)]
+impl RetryPolicy {
+    /// Send requests up to `max_attempts` times, with a delay of 100ms before the first retry, doubled for each retry up to 5s, and jitter.
+    pub fn new(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+    pub fn with_base_delay(self, base_delay: Duration) -> RetryPolicy {
+        RetryPolicy { base_delay, ..self }
+    }
+    pub fn with_max_delay(self, max_delay: Duration) -> RetryPolicy {
+        RetryPolicy { max_delay, ..self }
+    }
+    pub fn with_jitter(self, jitter: bool) -> RetryPolicy {
+        RetryPolicy { jitter, ..self }
+    }
+
+    /// The delay before the `retry`th retry, starting from 1.
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        use std::hash::{BuildHasher, Hasher};
+
+        let delay = self
+            .base_delay
+            .checked_mul(1 << retry.saturating_sub(1).min(16))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+        if self.jitter {
+            // Each `RandomState` is randomly seeded, which is random enough for a jitter
+            let random = std::collections::hash_map::RandomState::new().build_hasher().finish();
+            delay / 2 + delay.mul_f64((random % 1024) as f64 / 2048.0)
+        } else {
+            delay
+        }
+    }
+}
+
+/// The settings of the connections opened by a client, applied with [`Client::with_connection_pool`].
+///
+/// The unset settings keep the defaults of isahc: no limit on the number of connections, and up to 500 idle connections kept open.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::client::*;
+/// # use std::time::Duration;
+/// let connection_pool = ConnectionPool::new()
+///     .with_max_connections_per_host(32)
+///     .with_idle_timeout(Duration::from_secs(90))
+///     .with_tcp_keepalive(Duration::from_secs(30));
+/// let client = Client::new("http://localhost:7700", "masterKey").with_connection_pool(connection_pool).unwrap();
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConnectionPool {
+    /// The maximum number of connections open at the same time, to all hosts
+    pub max_connections: Option<usize>,
+    /// The maximum number of connections open at the same time to a single host
+    pub max_connections_per_host: Option<usize>,
+    /// The maximum number of idle connections kept open to be reused
+    pub max_idle_connections: Option<usize>,
+    /// How long an idle connection is kept open to be reused
+    pub idle_timeout: Option<Duration>,
+    /// The interval of the TCP keep-alive probes, keeping connections open through proxies and firewalls dropping idle connections
+    pub tcp_keepalive: Option<Duration>,
+    /// Only use HTTP/2, without negotiating it, when MeiliSearch is behind a proxy known to support it.
+    /// Otherwise, HTTP/2 is used when it's negotiated with TLS.
+    pub http2_prior_knowledge: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(missing_docs)]
+impl ConnectionPool {
+    pub fn new() -> ConnectionPool {
+        ConnectionPool::default()
+    }
+    pub fn with_max_connections(self, max_connections: usize) -> ConnectionPool {
+        ConnectionPool { max_connections: Some(max_connections), ..self }
+    }
+    pub fn with_max_connections_per_host(self, max_connections_per_host: usize) -> ConnectionPool {
+        ConnectionPool { max_connections_per_host: Some(max_connections_per_host), ..self }
+    }
+    pub fn with_max_idle_connections(self, max_idle_connections: usize) -> ConnectionPool {
+        ConnectionPool { max_idle_connections: Some(max_idle_connections), ..self }
+    }
+    pub fn with_idle_timeout(self, idle_timeout: Duration) -> ConnectionPool {
+        ConnectionPool { idle_timeout: Some(idle_timeout), ..self }
+    }
+    pub fn with_tcp_keepalive(self, tcp_keepalive: Duration) -> ConnectionPool {
+        ConnectionPool { tcp_keepalive: Some(tcp_keepalive), ..self }
+    }
+    pub fn with_http2_prior_knowledge(self, http2_prior_knowledge: bool) -> ConnectionPool {
+        ConnectionPool { http2_prior_knowledge, ..self }
+    }
+
+    fn http_client(&self) -> Result<isahc::HttpClient, Error> {
+        use isahc::config::{Configurable, VersionNegotiation};
+
+        let mut builder = isahc::HttpClient::builder();
+        if let Some(max_connections) = self.max_connections {
+            builder = builder.max_connections(max_connections);
+        }
+        if let Some(max_connections_per_host) = self.max_connections_per_host {
+            builder = builder.max_connections_per_host(max_connections_per_host);
+        }
+        if let Some(max_idle_connections) = self.max_idle_connections {
+            builder = builder.connection_cache_size(max_idle_connections);
+        }
+        if let Some(idle_timeout) = self.idle_timeout {
+            builder = builder.connection_cache_ttl(idle_timeout);
+        }
+        if let Some(tcp_keepalive) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(tcp_keepalive);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.version_negotiation(VersionNegotiation::http2());
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// A limit on the rate of the requests sent by a client, set with [`Client::with_rate_limit`], for example so that bulk imports don't overwhelm a MeiliSearch instance shared with search traffic.
+///
+/// Up to `burst` requests are sent right away, and then the requests wait so that at most `requests_per_second` are sent per second, on average.
+/// Retries count as requests.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::client::*;
+/// let client = Client::new("http://localhost:7700", "masterKey").with_rate_limit(Some(RateLimit::new(50.0, 10)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// The average number of requests sent per second, which must be positive.
+    /// Lower values, and NaN, are replaced with [`RateLimit::MIN_REQUESTS_PER_SECOND`].
+    pub requests_per_second: f64,
+    /// The number of requests that can be sent at once, after a pause
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// The lowest rate of requests, one every 1000 seconds, used instead of a rate that isn't positive.
+    pub const MIN_REQUESTS_PER_SECOND: f64 = 0.001;
+
+    /// A limit of `requests_per_second`, clamped to at least [`RateLimit::MIN_REQUESTS_PER_SECOND`], with bursts of `burst` requests.
+    pub fn new(requests_per_second: f64, burst: u32) -> RateLimit {
+        RateLimit {
+            // `f64::max` ignores NaN
+            requests_per_second: requests_per_second.max(RateLimit::MIN_REQUESTS_PER_SECOND),
+            burst,
+        }
+    }
+}
+
+/// Hooks called around every request sent by a client, added with [`Client::with_middleware`].
+///
+/// Middlewares are called in the order they were added, once per attempt when a request is [retried](RetryPolicy).
+/// Both methods do nothing by default.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::client::*;
+/// struct AuditLog;
+///
+/// impl Middleware for AuditLog {
+///     fn on_request(&self, request: &mut MiddlewareRequest) {
+///         request.headers.push(("X-Audit-Id".to_string(), "42".to_string()));
+///     }
+///
+///     fn on_response(&self, request: &MiddlewareRequest, response: &MiddlewareResponse) {
+///         println!("{} {}: {:?} in {:?}", request.method, request.url, response.status, response.duration);
+///     }
+/// }
+///
+/// let client = Client::new("http://localhost:7700", "masterKey").with_middleware(AuditLog);
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before a request is sent. The headers added to the request replace the ones with the same name.
+    fn on_request(&self, _request: &mut MiddlewareRequest) {}
+
+    /// Called once the response of a request is received, or once it failed.
+    fn on_response(&self, _request: &MiddlewareRequest, _response: &MiddlewareResponse) {}
+}
+
+impl std::fmt::Debug for dyn Middleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Middleware")
+    }
+}
+
+/// A request about to be sent, given to the [middlewares](Middleware).
+#[derive(Debug)]
+pub struct MiddlewareRequest<'a> {
+    /// The HTTP method, such as `GET`
+    pub method: &'a str,
+    pub url: &'a str,
+    /// The headers to set on the request, for example to refresh its authentication
+    pub headers: Vec<(String, String)>,
+}
+
+/// The outcome of a request, given to the [middlewares](Middleware).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiddlewareResponse {
+    /// The HTTP status of the response, or `None` if no response was received
+    pub status: Option<u16>,
+    /// The time elapsed between sending the request and receiving the response
+    pub duration: Duration,
+}
+
+/// An observer of the requests sent by a client, set with [`Client::with_metrics_observer`], to feed metrics such as request counters and latency histograms.
+///
+/// It is called once per attempt when a request is [retried](RetryPolicy), and is implemented for closures.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::client::*;
+/// let client = Client::new("http://localhost:7700", "masterKey").with_metrics_observer(|metrics: &RequestMetrics| {
+///     println!("{} {} -> {:?} in {:?}", metrics.method, metrics.endpoint, metrics.status, metrics.duration);
+/// });
+/// ```
+pub trait MetricsObserver: Send + Sync {
+    /// Called once the response of a request is received, or once it failed.
+    fn observe(&self, metrics: &RequestMetrics);
+}
+
+impl<F: Fn(&RequestMetrics) + Send + Sync> MetricsObserver for F {
+    fn observe(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl std::fmt::Debug for dyn MetricsObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("MetricsObserver")
+    }
+}
+
+/// The metrics of a request, given to the [`MetricsObserver`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMetrics<'a> {
+    /// The HTTP method, such as `GET`
+    pub method: &'a str,
+    /// The path of the request, such as `/indexes/movies/search`
+    pub endpoint: &'a str,
+    /// The uid of the index targeted by the request, if any
+    pub index_uid: Option<&'a str>,
+    /// The HTTP status of the response, or `None` if no response was received
+    pub status: Option<u16>,
+    /// The time elapsed between sending the request and receiving the response
+    pub duration: Duration,
+    /// The size of the request body in bytes, if known
+    pub request_size: Option<u64>,
+    /// The size of the response body in bytes, if known
+    pub response_size: Option<u64>,
+}
+
+/// A token cancelling the requests sent with it, set with [`Client::with_cancellation_token`] or [`Index::with_cancellation_token`](../indexes/struct.Index.html#method.with_cancellation_token).
+///
+/// Once [cancelled](CancellationToken::cancel), the pending and future requests sent with the token fail with [`Error::Cancelled`](../errors/enum.Error.html#variant.Cancelled).
+/// The requests are aborted, so that they don't keep using a connection: fetch requests are aborted with an `AbortController` in browsers.
+/// A token can be cloned and shared between many requests. Dropping a request future also aborts it.
+///
+/// # Example
+///
+/// ```no_run
+/// # use meilisearch_sdk::{client::*, errors::*};
+/// # futures::executor::block_on(async move {
+/// let client = Client::new("http://localhost:7700", "masterKey");
+///
+/// // Cancel the previous search when the user types a new character
+/// let token = CancellationToken::new();
+/// let movies = client.assume_index("movies").with_cancellation_token(Some(token.clone()));
+/// let search = movies.search().with_query("inters").execute::<serde_json::Value>();
+/// token.cancel();
+/// assert!(matches!(search.await, Err(Error::Cancelled)));
+/// # });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    state: std::sync::Arc<std::sync::Mutex<CancellationState>>,
+}
+
+#[derive(Debug, Default)]
+struct CancellationState {
+    cancelled: bool,
+    /// The wakers of the pending [`Cancelled`] futures, by the id of their slot
+    wakers: std::collections::HashMap<u64, std::task::Waker>,
+    next_slot: u64,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Cancel the requests sent with this token, and the ones that will be sent with it.
+    pub fn cancel(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.cancelled = true;
+        for (_, waker) in state.wakers.drain() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state.lock().unwrap().cancelled
+    }
+
+    /// Wait until the token is cancelled.
+    pub(crate) fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled { token: self, slot: None }
+    }
+}
+
+/// A future resolving once its token is cancelled.
+/// It keeps a single waker in the token, which is replaced when polled again and removed when the future is dropped,
+/// so that a long-lived token doesn't accumulate the wakers of the requests that completed.
+pub(crate) struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    slot: Option<u64>,
+}
+
+impl std::future::Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<()> {
+        let this = self.get_mut();
+        let mut state = this.token.state.lock().unwrap();
+        if state.cancelled {
+            return std::task::Poll::Ready(());
+        }
+
+        let slot = match this.slot {
+            Some(slot) => slot,
+            None => {
+                let slot = state.next_slot;
+                state.next_slot += 1;
+                this.slot = Some(slot);
+                slot
+            }
+        };
+        state.wakers.insert(slot, cx.waker().clone());
+        std::task::Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let (Some(slot), Ok(mut state)) = (self.slot, self.token.state.lock()) {
+            state.wakers.remove(&slot);
+        }
+    }
+}
+
+/// Remove the trailing slashes of a host, since the paths of the API are appended to it.
+fn base_url(mut host: String) -> String {
+    while host.ends_with('/') {
+        host.pop();
+    }
+    host
+}
+
+impl Compression {
+    pub(crate) fn content_encoding(&self) -> &'static str {
+        match self {
+            Compression::Gzip => "gzip",
+            Compression::Deflate => "deflate",
+            Compression::Brotli => "br",
+        }
+    }
 }
 
 impl Client {
     /// Create a client using the specified server.
-    /// Don't put a '/' at the end of the host.
+    /// The host can contain a path, when MeiliSearch is behind a reverse proxy serving it under a path prefix, such as `https://example.com/meilisearch`.
     /// In production mode, see [the documentation about authentication](https://docs.meilisearch.com/reference/features/authentication.html#authentication).
     /// # Example
     ///
@@ -24,11 +445,237 @@ impl Client {
     /// ```
     pub fn new(host: impl Into<String>, api_key: impl Into<String>) -> Client {
         Client {
-            host: Rc::new(host.into()),
-            api_key: Rc::new(api_key.into())
+            host: Rc::new(base_url(host.into())),
+            config: Rc::new(RequestConfig::new(api_key.into())),
+            compression: None,
         }
     }
 
+    /// Send the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it, with an existing [`isahc::HttpClient`].
+    ///
+    /// This allows reusing its configuration (proxy, TLS, connection pool, timeouts...) and its connections, instead of the default client of isahc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// use isahc::{config::Configurable, HttpClient};
+    /// use std::time::Duration;
+    ///
+    /// let http_client = HttpClient::builder()
+    ///     .max_connections_per_host(8)
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::new("http://localhost:7700", "masterKey").with_http_client(http_client);
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_http_client(self, http_client: isahc::HttpClient) -> Client {
+        self.with_config(|config| config.http_client = Some(http_client))
+    }
+
+    /// Send the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it, with a new [`isahc::HttpClient`] whose connections follow the [`ConnectionPool`] settings.
+    ///
+    /// It replaces the HTTP client set with [`Client::with_http_client`], if any.
+    /// See [`ConnectionPool`] for an example.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_connection_pool(self, connection_pool: ConnectionPool) -> Result<Client, Error> {
+        let http_client = connection_pool.http_client()?;
+        Ok(self.with_http_client(http_client))
+    }
+
+    /// Fail the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it, with [`Error::Timeout`](../errors/enum.Error.html#variant.Timeout)
+    /// if their response isn't received within `timeout`. There is no timeout by default.
+    ///
+    /// It can be changed for a single index with [`Index::with_timeout`](../indexes/struct.Index.html#method.with_timeout),
+    /// for example to allow large document uploads while keeping searches short.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// # use std::time::Duration;
+    /// let client = Client::new("http://localhost:7700", "masterKey").with_timeout(Some(Duration::from_secs(30)));
+    /// ```
+    pub fn with_timeout(self, timeout: Option<Duration>) -> Client {
+        self.with_config(|config| config.timeout = timeout)
+    }
+
+    /// Fail the requests of this client with [`Error::Timeout`](../errors/enum.Error.html#variant.Timeout) if the connection to the server takes longer than `connect_timeout`.
+    /// The default connection timeout of isahc is 5 minutes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// # use std::time::Duration;
+    /// let client = Client::new("http://localhost:7700", "masterKey").with_connect_timeout(Some(Duration::from_secs(2)));
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_connect_timeout(self, connect_timeout: Option<Duration>) -> Client {
+        self.with_config(|config| config.connect_timeout = connect_timeout)
+    }
+
+    /// Connect to MeiliSearch through a Unix socket, for example when it runs as a sidecar not listening on TCP.
+    ///
+    /// The host of the client is still sent in the `Host` header, and its path prefix, if any, is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// let client = Client::new("http://localhost", "masterKey").with_unix_socket("/var/run/meilisearch.sock");
+    /// ```
+    #[cfg(all(unix, not(target_arch = "wasm32")))]
+    pub fn with_unix_socket(self, unix_socket: impl Into<std::path::PathBuf>) -> Client {
+        self.with_config(|config| config.unix_socket = Some(unix_socket.into()))
+    }
+
+    /// Set the [credentials mode](https://developer.mozilla.org/en-US/docs/Web/API/Request/credentials) of the requests of this client, in browsers.
+    ///
+    /// Use [`RequestCredentials::Include`](web_sys::RequestCredentials::Include) when MeiliSearch is behind a gateway authenticating requests with cookies.
+    /// The browser's default is `same-origin`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// # use meilisearch_sdk::client::*;
+    /// use web_sys::RequestCredentials;
+    ///
+    /// let client = Client::new("https://search.example.com", "searchKey").with_credentials(Some(RequestCredentials::Include));
+    /// ```
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_credentials(self, credentials: Option<web_sys::RequestCredentials>) -> Client {
+        self.with_config(|config| config.credentials = credentials)
+    }
+
+    /// Set the [mode](https://developer.mozilla.org/en-US/docs/Web/API/Request/mode) of the requests of this client, in browsers, such as `cors` or `same-origin`.
+    /// The browser's default is `cors`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// # use meilisearch_sdk::client::*;
+    /// use web_sys::RequestMode;
+    ///
+    /// let client = Client::new("/search", "searchKey").with_request_mode(Some(RequestMode::SameOrigin));
+    /// ```
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_request_mode(self, mode: Option<web_sys::RequestMode>) -> Client {
+        self.with_config(|config| config.mode = mode)
+    }
+
+    /// Limit the rate of the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it, or stop limiting it with `None`.
+    ///
+    /// The limit is shared by the indexes and tasks obtained from the client.
+    pub fn with_rate_limit(self, rate_limit: Option<RateLimit>) -> Client {
+        let rate_limiter = rate_limit.map(|rate_limit| Rc::new(RateLimiter::new(rate_limit)));
+        self.with_config(|config| config.rate_limiter = rate_limiter)
+    }
+
+    /// Retry the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it, that fail because of a transient error.
+    /// See [`RetryPolicy`] for the requests that are retried. Requests are not retried by default.
+    pub fn with_retry_policy(self, retry_policy: Option<RetryPolicy>) -> Client {
+        self.with_config(|config| config.retry_policy = retry_policy)
+    }
+
+    /// Add a header to the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it.
+    ///
+    /// If the name or the value of the header is invalid, the requests fail with [`Error::InvalidRequest`](../errors/enum.Error.html#variant.InvalidRequest).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// let client = Client::new("http://localhost:7700", "masterKey")
+    ///     .with_header("X-Request-Id", "7f2c9d")
+    ///     .with_header("X-Tenant", "acme");
+    /// ```
+    pub fn with_header(self, name: impl Into<String>, value: impl Into<String>) -> Client {
+        self.with_config(|config| config.headers.push((name.into(), value.into())))
+    }
+
+    /// Identify the application in the `User-Agent` of the requests, after the one of the SDK (`Meilisearch Rust (v<version>); <user_agent>`).
+    ///
+    /// In browsers, the `X-Meilisearch-Client` header is used instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// let client = Client::new("http://localhost:7700", "masterKey").with_user_agent("movies-importer/1.2");
+    /// ```
+    pub fn with_user_agent(self, user_agent: impl Into<String>) -> Client {
+        self.with_config(|config| config.user_agent = Some(user_agent.into()))
+    }
+
+    /// Send the searches of this client, and of the [indexes](../indexes/struct.Index.html) obtained from it, to replicas of the MeiliSearch server too.
+    ///
+    /// Searches and document reads are spread between the host of the client and the `replicas`.
+    /// When a host can't be reached, times out or returns a `502`, `503` or `504` response, the request is sent to the next host, and the failing host is avoided for 30 seconds.
+    /// Other requests are always sent to the host of the client, the primary, since replicas can't be written to.
+    /// This includes the other reads, such as getting tasks or indexes, so that they see the writes made on the primary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// let client = Client::new("http://meilisearch-primary:7700", "masterKey")
+    ///     .with_replicas(["http://meilisearch-replica-1:7700", "http://meilisearch-replica-2:7700"]);
+    /// ```
+    pub fn with_replicas(self, replicas: impl IntoIterator<Item = impl Into<String>>) -> Client {
+        let replicas: Vec<String> = replicas.into_iter().map(|replica| base_url(replica.into())).collect();
+        let host_pool = if replicas.is_empty() {
+            None
+        } else {
+            Some(Rc::new(HostPool::new(self.host.to_string(), replicas)))
+        };
+        self.with_config(|config| config.host_pool = host_pool)
+    }
+
+    /// Cancel the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it, with a [`CancellationToken`].
+    ///
+    /// See [`CancellationToken`] for an example.
+    pub fn with_cancellation_token(self, cancellation_token: Option<CancellationToken>) -> Client {
+        self.with_config(|config| config.cancellation_token = cancellation_token)
+    }
+
+    /// Add a [middleware](Middleware) called around the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it.
+    ///
+    /// See [`Middleware`] for an example.
+    pub fn with_middleware(self, middleware: impl Middleware + 'static) -> Client {
+        self.with_config(|config| config.middlewares.push(Rc::new(middleware)))
+    }
+
+    /// Set the [observer](MetricsObserver) of the requests of this client, and of the [indexes](../indexes/struct.Index.html) and tasks obtained from it.
+    ///
+    /// See [`MetricsObserver`] for an example.
+    pub fn with_metrics_observer(self, metrics_observer: impl MetricsObserver + 'static) -> Client {
+        self.with_config(|config| config.metrics_observer = Some(Rc::new(metrics_observer)))
+    }
+
+    fn with_config(self, update: impl FnOnce(&mut RequestConfig)) -> Client {
+        let mut config = RequestConfig::clone(&self.config);
+        update(&mut config);
+        Client { config: Rc::new(config), ..self }
+    }
+
+    /// Compress the documents sent by the [indexes](../indexes/struct.Index.html) of this client, or stop compressing them with `None`.
+    ///
+    /// Compressing is worth it for large payloads, since documents usually compress well, but it costs some CPU time on both sides.
+    /// It can also be changed for a single index with [`Index::with_compression`](../indexes/struct.Index.html#method.with_compression).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// let client = Client::new("http://localhost:7700", "masterKey").with_compression(Some(Compression::Gzip));
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn with_compression(self, compression: Option<Compression>) -> Client {
+        Client { compression, ..self }
+    }
+
     /// List all [indexes](../indexes/struct.Index.html).
     ///
     /// # Example
@@ -46,7 +693,7 @@ impl Client {
     pub async fn list_all_indexes(&self) -> Result<Vec<Index>, Error> {
         let json_indexes = request::<(), Vec<JsonIndex>>(
             &format!("{}/indexes", self.host),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?;
@@ -69,7 +716,7 @@ impl Client {
     /// # futures::executor::block_on(async move {
     /// // create the client
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// # client.create_index("movies", None).await;
+    /// # client.get_or_create("movies").await.unwrap();
     ///
     /// // get the index named "movies"
     /// let movies = client.get_index("movies").await.unwrap();
@@ -78,7 +725,7 @@ impl Client {
     pub async fn get_index(&self, uid: impl AsRef<str>) -> Result<Index, Error> {
         Ok(request::<(), JsonIndex>(
             &format!("{}/indexes/{}", self.host, uid.as_ref()),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?
@@ -90,80 +737,207 @@ impl Client {
         Index {
             uid: Rc::new(uid.into()),
             host: Rc::clone(&self.host),
-            api_key: Rc::clone(&self.api_key)
+            config: Rc::clone(&self.config),
+            compression: self.compression,
+            max_payload_size: None,
+            upload_concurrency: 1,
         }
     }
 
+    /// Get the [index](../document/struct.DocumentIndex.html) storing a type of [documents](../document/trait.IndexedDocument.html), without any check.
+    /// The index is created by MeiliSearch when documents are first added to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "derive")] {
+    /// # use meilisearch_sdk::{client::*, document::*};
+    /// # use serde::{Serialize, Deserialize};
+    /// #[derive(Serialize, Deserialize, Debug, Document)]
+    /// #[document(index = "movies")]
+    /// struct Movie {
+    ///     id: usize,
+    ///     title: String,
+    /// }
+    ///
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.index_for::<Movie>();
+    /// # }
+    /// ```
+    pub fn index_for<T: 'static + IndexedDocument>(&self) -> DocumentIndex<T> {
+        DocumentIndex::new(self.assume_index(T::INDEX_UID))
+    }
+
     /// Create an [index](../indexes/struct.Index.html).
     /// The second parameter will be used as the primary key of the new index. If it is not specified, MeiliSearch will **try** to infer the primary key.
+    ///
+    /// The index is created asynchronously by a [task](../tasks/struct.Task.html), which fails if the index already exists.
+    /// Use [get_or_create](#method.get_or_create) to get an [`Index`] that is ready to be used.
+    ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, tasks::TaskStatus};
     /// #
     /// # futures::executor::block_on(async move {
     /// // create the client
     /// let client = Client::new("http://localhost:7700", "masterKey");
     ///
-    /// # if let Ok(mut movies) = client.get_index("movies").await {
-    /// #   movies.delete().await.unwrap();
-    /// # }
-    /// // create a new index called movies and access it
-    /// let movies = client.create_index("movies", None).await;
+    /// # client.delete_index_if_exists("movies_create").await.unwrap();
+    /// // create a new index called movies_create and access it
+    /// let task = client.create_index("movies_create", None).await.unwrap();
+    /// let task = task.wait_for_completion(&client, None, None).await.unwrap();
+    /// assert_eq!(task.status, TaskStatus::Succeeded);
+    /// let movies = client.get_index("movies_create").await.unwrap();
+    /// # client.delete_index("movies_create").await.unwrap();
     /// # });
     /// ```
     pub async fn create_index(
         &self,
         uid: impl AsRef<str>,
         primary_key: Option<&str>,
-    ) -> Result<Index, Error> {
-        Ok(request::<Value, JsonIndex>(
+    ) -> Result<TaskInfo, Error> {
+        request::<Value, TaskInfo>(
             &format!("{}/indexes", self.host),
-            &self.api_key,
+            &self.config,
             Method::Post(json!({
                 "uid": uid.as_ref(),
                 "primaryKey": primary_key,
             })),
-            201,
-        ).await?
-        .into_index(self))
+            202,
+        ).await
     }
 
-    /// Delete an index from its UID if it exists.
+    /// Delete an index from its UID if it exists, and wait for the deletion to be processed.
+    /// Returns whether the index was deleted.
     /// To delete an index if it exists from the [`Index`] object, use the [Index::delete_if_exists] method.
     pub async fn delete_index_if_exists(&self, uid: &str) -> Result<bool, Error> {
-        match self.delete_index(uid).await {
-            Ok (_) => Ok(true),
-            Err (Error::MeiliSearchError {
-                message: _,
-                error_code: ErrorCode::IndexNotFound,
-                error_type: _,
-                error_link: _,
-            }) => Ok(false),
-            Err(error) => Err(error),
-        }
+        self.assume_index(uid).delete_if_exists().await
     }
 
     /// Delete an index from its UID.
     /// To delete an index from the [index object](../indexes/struct.Index.html), use [the delete method](../indexes/struct.Index.html#method.delete).
-    pub async fn delete_index(&self, uid: impl AsRef<str>) -> Result<(), Error> {
-        Ok(request::<(), ()>(
+    ///
+    /// The index is deleted asynchronously by a [task](../tasks/struct.Task.html), which fails if the index doesn't exist.
+    pub async fn delete_index(&self, uid: impl AsRef<str>) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!("{}/indexes/{}", self.host, uid.as_ref()),
-            &self.api_key,
+            &self.config,
             Method::Delete,
-            204,
-        ).await?)
+            202,
+        ).await
+    }
+
+    /// Atomically swap the documents, settings and tasks of pairs of indexes, in a single [task](../tasks/struct.Task.html).
+    ///
+    /// This allows building a new version of an index in the background, then swapping it with the one being searched without downtime.
+    /// Both indexes of each pair must exist, and an index can't appear in several pairs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::TaskStatus};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # client.get_or_create("movies_swap").await.unwrap();
+    /// let movies_new = client.get_or_create("movies_swap_new").await.unwrap();
+    /// // ... fill and configure movies_swap_new
+    ///
+    /// let task = client.swap_indexes(&[("movies_swap", "movies_swap_new")]).await.unwrap();
+    /// let task = task.wait_for_completion(&client, None, None).await.unwrap();
+    /// assert_eq!(task.status, TaskStatus::Succeeded);
+    /// # client.delete_index("movies_swap").await.unwrap();
+    /// # client.delete_index("movies_swap_new").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn swap_indexes(&self, swaps: &[(&str, &str)]) -> Result<TaskInfo, Error> {
+        let swaps: Vec<Value> = swaps.iter().map(|(a, b)| json!({ "indexes": [a, b] })).collect();
+        request::<Vec<Value>, TaskInfo>(
+            &format!("{}/swap-indexes", self.host),
+            &self.config,
+            Method::Post(swaps),
+            202,
+        ).await
     }
 
     /// This will try to get an index and create the index if it does not exist.
+    /// If the index is created, this method waits for its creation to be processed.
     pub async fn get_or_create(&self, uid: impl AsRef<str>) -> Result<Index, Error> {
-        if let Ok(index) = self.get_index(uid.as_ref()).await {
-            Ok(index)
-        } else {
-            self.create_index(uid, None).await
+        match self.get_index(uid.as_ref()).await {
+            Err(Error::Meilisearch(MeilisearchError { code: ErrorCode::IndexNotFound, .. })) => {
+                let task = self.create_index(uid.as_ref(), None).await?.wait_for_completion(self, None, None).await?;
+                match task.error.map(MeilisearchError::from) {
+                    // The index was created concurrently
+                    Some(MeilisearchError { code: ErrorCode::IndexAlreadyExists, .. }) | None => self.get_index(uid.as_ref()).await,
+                    Some(error) => Err(Error::Meilisearch(error)),
+                }
+            }
+            result => result,
         }
     }
 
+    /// Make sure an index matches its [declaration](../indexes/struct.IndexSpec.html).
+    ///
+    /// The index is created if it doesn't exist, its primary key is set if it differs from the declared one,
+    /// and the settings that differ from the declared ones are updated.
+    /// This method waits for each of these tasks to be processed, and fails if one of them fails or times out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    ///
+    /// let spec = IndexSpec::new("movies_ensure")
+    ///     .with_primary_key("id")
+    ///     .with_settings(Settings::new().with_filterable_attributes(["genres"]));
+    /// let movies = client.ensure_index(&spec).await.unwrap();
+    /// # client.delete_index("movies_ensure").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn ensure_index(&self, spec: &IndexSpec) -> Result<Index, Error> {
+        let index = match request::<(), JsonIndex>(
+            &format!("{}/indexes/{}", self.host, spec.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await {
+            Ok(json_index) => {
+                let primary_key = json_index.primaryKey.clone();
+                let index = json_index.into_index(self);
+                if let Some(wanted) = &spec.primary_key {
+                    if primary_key.as_ref() != Some(wanted) {
+                        info!("Setting the primary key of index {} to {}", spec.uid, wanted);
+                        if let Some(error) = index.set_primary_key(wanted).await?.wait_with(None, spec.timeout).await?.error {
+                            return Err(Error::Meilisearch(error.into()));
+                        }
+                    }
+                }
+                index
+            }
+            Err(Error::Meilisearch(MeilisearchError { code: ErrorCode::IndexNotFound, .. })) => {
+                info!("Creating index {}", spec.uid);
+                let task = self.create_index(&spec.uid, spec.primary_key.as_deref()).await?;
+                if let Some(error) = self.wait_for_task(task.task_uid, None, spec.timeout).await?.error {
+                    return Err(Error::Meilisearch(error.into()));
+                }
+                self.assume_index(&spec.uid)
+            }
+            Err(error) => return Err(error),
+        };
+
+        let diff = index.get_settings().await?.diff(&spec.settings);
+        if !diff.is_empty() {
+            info!("Updating settings {:?} of index {}", diff.changed, spec.uid);
+            if let Some(error) = index.set_settings(&diff.changes).await?.wait_with(None, spec.timeout).await?.error {
+                return Err(Error::Meilisearch(error.into()));
+            }
+        }
+
+        Ok(index)
+    }
+
     /// Alias for [list_all_indexes](#method.list_all_indexes).
     pub async fn get_indexes(&self) -> Result<Vec<Index>, Error> {
         self.list_all_indexes().await
@@ -184,7 +958,7 @@ impl Client {
     pub async fn get_stats(&self) -> Result<ClientStats, Error> {
         request::<serde_json::Value, ClientStats>(
             &format!("{}/stats", self.host),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await
@@ -205,7 +979,7 @@ impl Client {
     pub async fn health(&self) -> Result<Health, Error> {
         request::<serde_json::Value, Health>(
             &format!("{}/health", self.host),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         )
@@ -227,31 +1001,64 @@ impl Client {
     /// ```
     pub async fn is_healthy(&self) -> bool {
         if let Ok(health) = self.health().await {
-            health.status.as_str() == "available"
+            health.is_available()
         } else {
             false
         }
     }
 
-    /// Get the private and public key.
+    /// Execute [queries](../search/struct.Query.html) on multiple indexes concurrently.
+    ///
+    /// At most `max_concurrency` queries are sent at the same time.
+    /// Every result is returned with the uid of the index it was searched in, in the order of `queries`, so a failing query doesn't prevent getting the results of the others.
+    /// Queries on the same index are executed separately, and their results are returned as separate entries with the same uid.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, errors::Error};
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
     /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Item {
+    ///     id: usize,
+    ///     name: String,
+    /// }
+    ///
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let keys = client.get_keys().await.unwrap();
+    /// let movies = client.assume_index("movies");
+    /// let books = client.assume_index("books");
+    ///
+    /// let queries = [
+    ///     Query::new(&movies).with_query("harry").build(),
+    ///     Query::new(&books).with_query("harry").build(),
+    /// ];
+    /// let results = client.search_many::<Item>(&queries, 4).await;
+    /// for (index_uid, result) in results {
+    ///     match result {
+    ///         Ok(result) => println!("{}: {} hits", index_uid, result.hits.len()),
+    ///         Err(error) => println!("{}: {:?}", index_uid, error),
+    ///     }
+    /// }
     /// # });
     /// ```
-    pub async fn get_keys(&self) -> Result<Keys, Error> {
-        request::<(), Keys>(
-            &format!("{}/keys", self.host),
-            &self.api_key,
-            Method::Get,
-            200,
-        ).await
+    pub async fn search_many<T: 'static + DeserializeOwned>(
+        &self,
+        queries: &[Query<'_>],
+        max_concurrency: usize,
+    ) -> Vec<(String, Result<SearchResults<T>, Error>)> {
+        stream::iter(queries)
+            .map(|query| async move {
+                let result = match query.build_owned() {
+                    Ok(query) => query.execute::<T>().await,
+                    Err(error) => Err(error),
+                };
+                (query.index().uid.to_string(), result)
+            })
+            .buffered(max_concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// Get version of the MeiliSearch server.
@@ -269,18 +1076,22 @@ impl Client {
     pub async fn get_version(&self) -> Result<Version, Error> {
         request::<(), Version>(
             &format!("{}/version", self.host),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await
     }
 }
 
-#[derive(Deserialize)]
+/// Statistics of the MeiliSearch instance, returned by [`Client::get_stats`].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ClientStats {
+    /// The size of the database, in bytes
     pub database_size: usize,
+    /// The date of the last update of any index
     pub last_update: Option<String>,
+    /// The statistics of each index, by uid
     pub indexes: HashMap<String, IndexStats>,
 }
 
@@ -294,16 +1105,16 @@ pub struct ClientStats {
 ///    status: "available".to_string(),
 /// };
 /// ```
-#[derive(Deserialize)]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Health {
     pub status: String,
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Keys {
-    pub public: Option<String>,
-    pub private: Option<String>,
+impl Health {
+    /// Whether the server is available, which is what [`Client::is_healthy`] checks.
+    pub fn is_available(&self) -> bool {
+        self.status == "available"
+    }
 }
 
 /// Version of a MeiliSearch server.
@@ -318,7 +1129,7 @@ pub struct Keys {
 ///    pkg_version: "0.1.1".to_string(),
 /// };
 /// ```
-#[derive(Deserialize)]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Version {
     pub commit_sha: String,
@@ -337,11 +1148,24 @@ mod tests {
         client.get_keys().await.unwrap();
     }
 
+    #[async_test]
+    async fn test_health_and_version() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        assert!(client.health().await.unwrap().is_available());
+        assert!(client.is_healthy().await);
+
+        let version = client.get_version().await.unwrap();
+        assert!(!version.pkg_version.is_empty());
+
+        let unreachable = Client::new("http://localhost:1", "masterKey");
+        assert!(!unreachable.is_healthy().await);
+    }
+
     #[async_test]
     async fn test_delete_if_exits() {
         let client = Client::new("http://localhost:7700", "masterKey");
         let index_name = "movies_delete_if_exists";
-        client.create_index(index_name, None).await.unwrap();
+        client.create_index(index_name, None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
         let mut index = client.get_index(index_name).await;
         assert!(index.is_ok());
         let deleted = client.delete_index_if_exists(index_name).await.unwrap();
@@ -350,6 +1174,305 @@ mod tests {
         assert!(index.is_err());
     }
 
+    #[async_test]
+    async fn test_search_many() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Document {
+            id: usize,
+            value: String,
+        }
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_search_many").await.unwrap();
+        client.delete_index_if_exists("test_search_many_missing").await.unwrap();
+        let index = client.get_or_create("test_search_many").await.unwrap();
+        index.add_documents(&[
+            Document { id: 0, value: "The Social Network".to_string() },
+            Document { id: 1, value: "Harry Potter and the Sorcerer's Stone".to_string() },
+        ], None).await.unwrap().wait().await.unwrap();
+        let missing = client.assume_index("test_search_many_missing");
+
+        let queries = [
+            Query::new(&index).with_query("harry").build(),
+            Query::new(&missing).with_query("harry").build(),
+            Query::new(&index).with_query("social").build(),
+        ];
+        let results = client.search_many::<Document>(&queries, 2).await;
+        assert_eq!(
+            results.iter().map(|(uid, _)| uid.as_str()).collect::<Vec<_>>(),
+            vec!["test_search_many", "test_search_many_missing", "test_search_many"]
+        );
+        let hits = &results[0].1.as_ref().unwrap().hits;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].result.id, 1);
+        assert_eq!(hits[0].result.value, "Harry Potter and the Sorcerer's Stone");
+        assert!(results[1].1.is_err());
+        assert_eq!(results[2].1.as_ref().unwrap().hits[0].result.id, 0);
+
+        client.delete_index("test_search_many").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_ensure_index() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_ensure_index").await.unwrap();
+
+        let spec = IndexSpec::new("test_ensure_index")
+            .with_primary_key("id")
+            .with_settings(crate::settings::Settings::new().with_sortable_attributes(["price"]));
+        let index = client.ensure_index(&spec).await.unwrap();
+        assert_eq!(index.get_sortable_attributes().await.unwrap(), vec!["price"]);
+
+        // Ensuring again is a no-op, and changed settings are applied
+        client.ensure_index(&spec).await.unwrap();
+        let spec = spec.with_settings(crate::settings::Settings::new().with_sortable_attributes(["rank"]));
+        let index = client.ensure_index(&spec).await.unwrap();
+        assert_eq!(index.get_sortable_attributes().await.unwrap(), vec!["rank"]);
+
+        client.delete_index("test_ensure_index").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_swap_indexes() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let a = client.get_or_create("test_swap_indexes_a").await.unwrap();
+        client.get_or_create("test_swap_indexes_b").await.unwrap();
+        a.set_stop_words(&["a"][..]).await.unwrap().wait().await.unwrap();
+
+        let task = client.swap_indexes(&[("test_swap_indexes_a", "test_swap_indexes_b")]).await.unwrap();
+        let task = task.wait_for_completion(&client, None, None).await.unwrap();
+        assert_eq!(task.status, crate::tasks::TaskStatus::Succeeded);
+        assert!(client.assume_index("test_swap_indexes_a").get_stop_words().await.unwrap().is_empty());
+        assert_eq!(client.assume_index("test_swap_indexes_b").get_stop_words().await.unwrap(), vec!["a"]);
+
+        client.delete_index("test_swap_indexes_a").await.unwrap();
+        client.delete_index("test_swap_indexes_b").await.unwrap();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[async_test]
+    async fn test_with_http_client() {
+        use isahc::{config::Configurable, HttpClient};
+
+        let http_client = HttpClient::builder().timeout(std::time::Duration::from_secs(10)).build().unwrap();
+        let client = Client::new("http://localhost:7700", "masterKey").with_http_client(http_client);
+        assert!(client.is_healthy().await);
+        let index = client.get_or_create("test_with_http_client").await.unwrap();
+        index.get_stats().await.unwrap();
+        client.delete_index("test_with_http_client").await.unwrap();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[async_test]
+    async fn test_with_connection_pool() {
+        let connection_pool = ConnectionPool::new()
+            .with_max_connections(4)
+            .with_max_connections_per_host(2)
+            .with_max_idle_connections(2)
+            .with_idle_timeout(Duration::from_secs(30))
+            .with_tcp_keepalive(Duration::from_secs(10));
+        let client = Client::new("http://localhost:7700", "masterKey").with_connection_pool(connection_pool).unwrap();
+        let results = futures::future::join_all((0..8).map(|_| client.health())).await;
+        assert!(results.iter().all(Result::is_ok));
+    }
+
+    #[async_test]
+    async fn test_timeout() {
+        let client = Client::new("http://localhost:7700", "masterKey").with_timeout(Some(Duration::from_secs(10)));
+        assert!(client.is_healthy().await);
+
+        // A server accepting connections but never responding
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = Client::new(format!("http://{}", listener.local_addr().unwrap()), "masterKey");
+        let index = client.assume_index("test_timeout").with_timeout(Some(Duration::from_millis(100)));
+        assert!(matches!(index.get_stats().await, Err(Error::Timeout)));
+    }
+
+    #[test]
+    fn test_base_url() {
+        assert_eq!(*Client::new("http://localhost:7700", "masterKey").host, "http://localhost:7700");
+        assert_eq!(*Client::new("http://localhost:7700/", "masterKey").host, "http://localhost:7700");
+        assert_eq!(*Client::new("https://example.com/meili/", "masterKey").host, "https://example.com/meili");
+
+        let index = Client::new("https://example.com/meili/", "masterKey").assume_index("movies");
+        assert_eq!(format!("{}/indexes/{}", index.host, index.uid), "https://example.com/meili/indexes/movies");
+    }
+
+    #[cfg(unix)]
+    #[async_test]
+    async fn test_unix_socket() {
+        use std::io::{Read, Write};
+
+        let path = std::env::temp_dir().join(format!("meilisearch-sdk-test-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            let body = r#"{"status":"available"}"#;
+            write!(stream, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+        });
+
+        let client = Client::new("http://localhost", "masterKey").with_unix_socket(&path);
+        assert!(client.is_healthy().await);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[async_test]
+    async fn test_replicas() {
+        // A host that can't be reached, whose read requests are sent to the replica
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let client = Client::new(host, "masterKey").with_replicas(["http://localhost:7700"]);
+        let index = client.assume_index("test_replicas");
+        for _ in 0..3 {
+            // The replica answers that the index doesn't exist
+            let result = index.search().execute::<serde_json::Value>().await;
+            assert!(matches!(result, Err(Error::Meilisearch(_))));
+        }
+        assert!(matches!(client.get_task(0).await, Err(Error::UnreachableServer)));
+        assert!(matches!(client.create_index("test_replicas", None).await, Err(Error::UnreachableServer)));
+    }
+
+    #[async_test]
+    async fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let client = Client::new("http://localhost:7700", "masterKey").with_cancellation_token(Some(token));
+        assert!(matches!(client.health().await, Err(Error::Cancelled)));
+
+        // A server accepting connections but never responding
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let token = CancellationToken::new();
+        let client = Client::new(format!("http://{}", listener.local_addr().unwrap()), "masterKey")
+            .with_cancellation_token(Some(token.clone()));
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            token.cancel();
+        });
+        assert!(matches!(client.assume_index("test_cancellation_token").get_stats().await, Err(Error::Cancelled)));
+    }
+
+    #[test]
+    fn test_cancellation_token_wakers() {
+        use std::{future::Future, pin::Pin, task::Context};
+
+        let token = CancellationToken::new();
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        // Polling again replaces the waker, and dropping the future removes it
+        for _ in 0..10 {
+            let mut cancelled = token.cancelled();
+            assert!(Pin::new(&mut cancelled).poll(&mut cx).is_pending());
+            assert!(Pin::new(&mut cancelled).poll(&mut cx).is_pending());
+            assert_eq!(token.state.lock().unwrap().wakers.len(), 1);
+        }
+        assert!(token.state.lock().unwrap().wakers.is_empty());
+
+        let mut cancelled = token.cancelled();
+        assert!(Pin::new(&mut cancelled).poll(&mut cx).is_pending());
+        token.cancel();
+        assert!(token.state.lock().unwrap().wakers.is_empty());
+        assert!(Pin::new(&mut cancelled).poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let retry_policy = RetryPolicy::new(10).with_max_delay(Duration::from_millis(500)).with_jitter(false);
+        assert_eq!(retry_policy.delay(1), Duration::from_millis(100));
+        assert_eq!(retry_policy.delay(2), Duration::from_millis(200));
+        assert_eq!(retry_policy.delay(3), Duration::from_millis(400));
+        assert_eq!(retry_policy.delay(4), Duration::from_millis(500));
+        assert_eq!(retry_policy.delay(100), Duration::from_millis(500));
+
+        let retry_policy = retry_policy.with_jitter(true);
+        for retry in 1..5 {
+            let delay = retry_policy.delay(retry);
+            assert!(delay >= retry_policy.with_jitter(false).delay(retry) / 2);
+            assert!(delay <= retry_policy.with_jitter(false).delay(retry));
+        }
+    }
+
+    #[async_test]
+    async fn test_retry_unreachable_server() {
+        // A port on which nothing listens
+        let address = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let retry_policy = RetryPolicy::new(3).with_base_delay(Duration::from_millis(50)).with_jitter(false);
+        let client = Client::new(format!("http://{}", address), "masterKey").with_retry_policy(Some(retry_policy));
+
+        let start = std::time::Instant::now();
+        assert!(matches!(client.health().await, Err(Error::UnreachableServer)));
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[async_test]
+    async fn test_headers() {
+        let client = Client::new("http://localhost:7700", "masterKey")
+            .with_header("X-Request-Id", "test_headers")
+            .with_user_agent("test_headers");
+        assert_eq!(client.config.user_agent(), format!("Meilisearch Rust (v{}); test_headers", env!("CARGO_PKG_VERSION")));
+        assert!(client.is_healthy().await);
+
+        let client = Client::new("http://localhost:7700", "masterKey").with_header("Invalid\nName", "value");
+        assert!(matches!(client.health().await, Err(Error::InvalidRequest)));
+    }
+
+    #[async_test]
+    async fn test_middleware() {
+        use std::sync::{Arc, Mutex};
+
+        struct RefreshKey {
+            calls: Arc<Mutex<Vec<(String, Option<u16>)>>>,
+        }
+
+        impl Middleware for RefreshKey {
+            fn on_request(&self, request: &mut MiddlewareRequest) {
+                request.headers.push(("Authorization".to_string(), "Bearer masterKey".to_string()));
+            }
+
+            fn on_response(&self, request: &MiddlewareRequest, response: &MiddlewareResponse) {
+                self.calls.lock().unwrap().push((format!("{} {}", request.method, request.url), response.status));
+            }
+        }
+
+        // The middleware replaces the invalid key
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::new("http://localhost:7700", "invalidKey").with_middleware(RefreshKey { calls: Arc::clone(&calls) });
+        client.get_keys().await.unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec![("GET http://localhost:7700/keys".to_string(), Some(200))]);
+    }
+
+    #[async_test]
+    async fn test_metrics_observer() {
+        use std::sync::{Arc, Mutex};
+
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::new("http://localhost:7700", "masterKey").with_metrics_observer({
+            let observed = Arc::clone(&observed);
+            move |metrics: &RequestMetrics| {
+                observed.lock().unwrap().push((
+                    metrics.method.to_string(),
+                    metrics.endpoint.to_string(),
+                    metrics.index_uid.map(str::to_string),
+                    metrics.status,
+                ));
+            }
+        });
+        client.get_index("test_metrics_observer_missing").await.unwrap_err();
+        assert_eq!(
+            *observed.lock().unwrap(),
+            vec![(
+                "GET".to_string(),
+                "/indexes/test_metrics_observer_missing".to_string(),
+                Some("test_metrics_observer_missing".to_string()),
+                Some(404)
+            )]
+        );
+    }
+
     #[async_test]
     async fn test_delete_if_exits_none() {
         let client = Client::new("http://localhost:7700", "masterKey");
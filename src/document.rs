@@ -1,5 +1,11 @@
-use serde::{de::DeserializeOwned, Serialize};
-use std::fmt::Display;
+use crate::{errors::{Error, InvalidDocument}, indexes::Index, progress::TaskHandle, search::{Filter, SearchResults}};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Display, marker::PhantomData, ops::Deref};
+
+/// Derive the [`Document`](trait.Document.html) and [`IndexedDocument`](trait.IndexedDocument.html) traits.
+/// Requires the `derive` feature.
+#[cfg(feature = "derive")]
+pub use meilisearch_sdk_macros::Document;
 
 /// Documents are not a predefined structure.
 /// You can use your structs as documents by implementing that trait.
@@ -39,4 +45,427 @@ pub trait Document: DeserializeOwned + std::fmt::Debug + Serialize {
     /// **WARNING**! This method **MUST** only return an object that displays himself only using alphanumeric characters, '/' and '-'.
     /// Otherwise, the MeiliSearch server will reject your document.
     fn get_uid(&self) -> &Self::UIDType;
+
+    /// Check the document before it is sent by [`add_documents`](../indexes/struct.Index.html#method.add_documents) and the related methods,
+    /// so that a malformed document doesn't make the whole indexing task fail.
+    /// Return the reason why the document is invalid, if it is.
+    ///
+    /// All documents are valid by default.
+    /// The partial documents sent by [`update_documents`](../indexes/struct.Index.html#method.update_documents) don't have to implement `Document`, so they are not validated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::document::*;
+    /// # use serde::{Serialize, Deserialize};
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///    id: String,
+    ///    title: String,
+    /// }
+    ///
+    /// impl Document for Movie {
+    ///    type UIDType = String;
+    ///    fn get_uid(&self) -> &Self::UIDType {
+    ///        &self.id
+    ///    }
+    ///
+    ///    fn validate(&self) -> Result<(), String> {
+    ///        if self.id.is_empty() || !self.id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+    ///            return Err(format!("invalid id {:?}", self.id));
+    ///        }
+    ///        if self.title.is_empty() {
+    ///            return Err(String::from("missing title"));
+    ///        }
+    ///        Ok(())
+    ///    }
+    /// }
+    /// ```
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Validate all the documents, returning an error listing the invalid ones.
+pub(crate) fn validate_documents<T: Document>(documents: &[T]) -> Result<(), Error> {
+    let invalid_documents: Vec<InvalidDocument> = documents
+        .iter()
+        .enumerate()
+        .filter_map(|(position, document)| {
+            document.validate().err().map(|reason| InvalidDocument {
+                position,
+                uid: document.get_uid().to_string(),
+                reason,
+            })
+        })
+        .collect();
+    if invalid_documents.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidDocuments(invalid_documents))
+    }
+}
+
+/// [Documents](trait.Document.html) stored in a known index, under a known primary key.
+///
+/// With the `derive` feature, this trait can be derived together with [`Document`](trait.Document.html),
+/// using the `#[document(index = "...", primary_key = "...")]` attribute. `primary_key` is the name of the Rust field, and defaults to `id`.
+/// Such documents can then be handled through a [`DocumentIndex`], obtained with [`Client::index_for`](../client/struct.Client.html#method.index_for).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use meilisearch_sdk::{client::*, document::*};
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, Document)]
+/// #[document(index = "movies_indexed_document", primary_key = "movie_id")]
+/// #[serde(rename_all = "camelCase")]
+/// struct Movie {
+///     movie_id: String,
+///     title: String,
+/// }
+///
+/// assert_eq!(Movie::INDEX_UID, "movies_indexed_document");
+/// assert_eq!(Movie::PRIMARY_KEY, "movieId");
+///
+/// # futures::executor::block_on(async move {
+/// let client = Client::new("http://localhost:7700", "masterKey");
+/// let movies = client.index_for::<Movie>();
+/// movies.add(&[Movie { movie_id: String::from("interstellar"), title: String::from("Interstellar") }]).await.unwrap().wait().await.unwrap();
+/// let interstellar: Movie = movies.get(String::from("interstellar")).await.unwrap();
+/// # client.delete_index("movies_indexed_document").await.unwrap();
+/// # });
+/// # }
+/// ```
+pub trait IndexedDocument: Document {
+    /// The uid of the index storing the documents
+    const INDEX_UID: &'static str;
+    /// The name of the primary key attribute, as serialized
+    const PRIMARY_KEY: &'static str;
+}
+
+/// An [index](../indexes/struct.Index.html) storing [documents](trait.IndexedDocument.html) of type `T`, obtained with [`Client::index_for`](../client/struct.Client.html#method.index_for).
+///
+/// It provides typed versions of the most common methods, and dereferences to the underlying [`Index`] for the others.
+#[derive(Debug)]
+pub struct DocumentIndex<T> {
+    index: Index,
+    document_type: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static + IndexedDocument> DocumentIndex<T> {
+    pub(crate) fn new(index: Index) -> DocumentIndex<T> {
+        DocumentIndex { index, document_type: PhantomData }
+    }
+
+    /// The underlying index.
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Add documents, or replace them if they already exist, declaring the [primary key](trait.IndexedDocument.html#associatedconstant.PRIMARY_KEY).
+    /// See [`Index::add_or_replace`](../indexes/struct.Index.html#method.add_or_replace).
+    pub async fn add(&self, documents: &[T]) -> Result<TaskHandle, Error> {
+        self.index.add_or_replace(documents, Some(T::PRIMARY_KEY)).await
+    }
+
+    /// Partially update documents, or add them if they don't exist yet, declaring the [primary key](trait.IndexedDocument.html#associatedconstant.PRIMARY_KEY).
+    /// See [`Index::update_documents`](../indexes/struct.Index.html#method.update_documents).
+    pub async fn update(&self, documents: &[T]) -> Result<TaskHandle, Error> {
+        self.index.update_documents(documents, Some(T::PRIMARY_KEY)).await
+    }
+
+    /// Get a document using its unique id.
+    pub async fn get(&self, uid: T::UIDType) -> Result<T, Error> {
+        self.index.get_document::<T>(uid).await
+    }
+
+    /// Search the documents with a text query, using the default search parameters.
+    /// For other parameters, use [`Index::search`](../indexes/struct.Index.html#method.search).
+    pub async fn search(&self, query: &str) -> Result<SearchResults<T>, Error> {
+        self.index.search().with_query(query).execute::<T>().await
+    }
+}
+
+// Not derived, since the documents don't have to be Clone
+impl<T> Clone for DocumentIndex<T> {
+    fn clone(&self) -> DocumentIndex<T> {
+        DocumentIndex { index: self.index.clone(), document_type: PhantomData }
+    }
+}
+
+impl<T> Deref for DocumentIndex<T> {
+    type Target = Index;
+
+    fn deref(&self) -> &Index {
+        &self.index
+    }
+}
+
+/// A query selecting a page of the documents of an [index](../indexes/struct.Index.html), without searching them.
+/// You can add parameters using the builder syntax.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, document::*, search::Filter};
+/// # futures::executor::block_on(async move {
+/// let client = Client::new("http://localhost:7700", "masterKey");
+/// # let movies = client.get_or_create("movies_documents_query").await.unwrap();
+/// # movies.set_filterable_attributes(["genre"]).await.unwrap().wait().await.unwrap();
+///
+/// let horror_titles = DocumentsQuery::new(&movies)
+///     .with_filter(Filter::Expression("genre = horror"))
+///     .with_fields(&["title"])
+///     .with_limit(50)
+///     .execute::<serde_json::Value>()
+///     .await
+///     .unwrap();
+/// # client.delete_index("movies_documents_query").await.unwrap();
+/// # });
+/// ```
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentsQuery<'a> {
+    #[serde(skip_serializing)]
+    index: &'a Index,
+    /// The number of documents to skip.
+    ///
+    /// Default: `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    /// The maximum number of documents returned.
+    ///
+    /// Default: `20`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// The attributes to retrieve in the documents.
+    ///
+    /// Default: all attributes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<&'a [&'a str]>,
+    /// Only select the documents matching this filter.
+    /// The filtered attributes must be [filterable](../settings/struct.Settings.html#structfield.filterable_attributes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter<'a>>,
+}
+
+#[allow(missing_docs)]
+impl<'a> DocumentsQuery<'a> {
+    pub fn new(index: &'a Index) -> DocumentsQuery<'a> {
+        DocumentsQuery {
+            index,
+            offset: None,
+            limit: None,
+            fields: None,
+            filter: None,
+        }
+    }
+    pub fn with_offset<'b>(&'b mut self, offset: usize) -> &'b mut DocumentsQuery<'a> {
+        self.offset = Some(offset);
+        self
+    }
+    pub fn with_limit<'b>(&'b mut self, limit: usize) -> &'b mut DocumentsQuery<'a> {
+        self.limit = Some(limit);
+        self
+    }
+    pub fn with_fields<'b>(&'b mut self, fields: &'a [&'a str]) -> &'b mut DocumentsQuery<'a> {
+        self.fields = Some(fields);
+        self
+    }
+    pub fn with_filter<'b>(&'b mut self, filter: impl Into<Filter<'a>>) -> &'b mut DocumentsQuery<'a> {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Alias for [Index::fetch_documents](../indexes/struct.Index.html#method.fetch_documents).
+    pub async fn execute<T: 'static + DeserializeOwned>(&self) -> Result<DocumentsResults<T>, Error> {
+        self.index.fetch_documents::<T>(self).await
+    }
+}
+
+/// A page of documents, returned by a [`DocumentsQuery`].
+#[derive(Deserialize, Debug, Clone)]
+pub struct DocumentsResults<T> {
+    /// The documents of the page
+    pub results: Vec<T>,
+    /// The number of documents skipped
+    pub offset: usize,
+    /// The maximum number of documents in the page
+    pub limit: usize,
+    /// The total number of documents matching the query
+    pub total: usize,
+}
+
+/// The `_vectors` field of a document, holding its embeddings for each embedder of the index.
+/// See the [embedders](../settings/struct.Settings.html#structfield.embedders) setting.
+///
+/// The vectors of a `userProvided` embedder must be provided with each document.
+/// For other embedders, they can be provided to avoid computing them, in which case `regenerate` should be `false`.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::document::*;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize, Debug)]
+/// struct Movie {
+///     id: usize,
+///     title: String,
+///     #[serde(rename = "_vectors", default, skip_serializing_if = "Vectors::is_empty")]
+///     vectors: Vectors,
+/// }
+///
+/// let movie = Movie {
+///     id: 1,
+///     title: String::from("Interstellar"),
+///     vectors: Vectors::new().with_vector("image", vec![0.1, 0.2, 0.3]),
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&movie).unwrap(),
+///     r#"{"id":1,"title":"Interstellar","_vectors":{"image":{"embeddings":[0.1,0.2,0.3],"regenerate":false}}}"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(transparent)]
+pub struct Vectors(pub HashMap<String, EmbedderVectors>);
+
+impl Vectors {
+    /// Create an empty `_vectors` field.
+    pub fn new() -> Vectors {
+        Vectors(HashMap::new())
+    }
+
+    /// Set the vector of the document for an embedder. It won't be regenerated.
+    pub fn with_vector(self, embedder: impl Into<String>, vector: Vec<f32>) -> Vectors {
+        self.with(embedder, EmbedderVectors { embeddings: Some(Embeddings::Single(vector)), regenerate: false })
+    }
+
+    /// Set several vectors of the document for an embedder, for example one per paragraph. They won't be regenerated.
+    pub fn with_vectors(self, embedder: impl Into<String>, vectors: Vec<Vec<f32>>) -> Vectors {
+        self.with(embedder, EmbedderVectors { embeddings: Some(Embeddings::Multiple(vectors)), regenerate: false })
+    }
+
+    /// Set the vectors of the document for an embedder.
+    pub fn with(mut self, embedder: impl Into<String>, vectors: EmbedderVectors) -> Vectors {
+        self.0.insert(embedder.into(), vectors);
+        self
+    }
+
+    /// Get the vectors of the document for an embedder.
+    pub fn get(&self, embedder: &str) -> Option<&EmbedderVectors> {
+        self.0.get(embedder)
+    }
+
+    /// Whether there are no vectors for any embedder.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The vectors of a document for an embedder, in a [`Vectors`] field.
+///
+/// MeiliSearch also accepts a bare array of numbers, or `null`: both are deserialized as vectors that won't be regenerated.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct EmbedderVectors {
+    /// The vectors, if they are provided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeddings: Option<Embeddings>,
+    /// Whether MeiliSearch should compute the vectors again when the document is updated.
+    /// It must be `false` to keep user-provided vectors.
+    pub regenerate: bool,
+}
+
+impl<'de> Deserialize<'de> for EmbedderVectors {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<EmbedderVectors, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Representation {
+            Null(()),
+            Embeddings(Embeddings),
+            Explicit {
+                embeddings: Option<Embeddings>,
+                #[serde(default)]
+                regenerate: bool,
+            },
+        }
+
+        Ok(match Representation::deserialize(deserializer)? {
+            Representation::Null(()) => EmbedderVectors { embeddings: None, regenerate: false },
+            Representation::Embeddings(embeddings) => EmbedderVectors { embeddings: Some(embeddings), regenerate: false },
+            Representation::Explicit { embeddings, regenerate } => EmbedderVectors { embeddings, regenerate },
+        })
+    }
+}
+
+/// One or several vectors of a document, in [`EmbedderVectors`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Embeddings {
+    /// A single vector
+    Single(Vec<f32>),
+    /// Several vectors, which must have the same dimensions
+    Multiple(Vec<Vec<f32>>),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::*, document::*, tasks::TaskStatus};
+    use futures_await_test::async_test;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq, meilisearch_sdk_macros::Document)]
+    #[document(index = "test_derive_document", primary_key = "reference")]
+    struct Product {
+        #[serde(rename = "ref")]
+        reference: u32,
+        name: String,
+    }
+
+    #[test]
+    fn test_derive_document() {
+        assert_eq!(Product::INDEX_UID, "test_derive_document");
+        assert_eq!(Product::PRIMARY_KEY, "ref");
+        let product = Product { reference: 4, name: String::from("chair") };
+        assert_eq!(product.get_uid(), &4);
+    }
+
+    #[test]
+    fn test_vectors_serde() {
+        let vectors = Vectors::new()
+            .with_vector("default", vec![0.5, 1.0])
+            .with_vectors("paragraphs", vec![vec![0.0, 1.0], vec![1.0, 0.0]])
+            .with("generated", EmbedderVectors { embeddings: None, regenerate: true });
+        assert_eq!(serde_json::to_value(&vectors).unwrap(), serde_json::json!({
+            "default": { "embeddings": [0.5, 1.0], "regenerate": false },
+            "paragraphs": { "embeddings": [[0.0, 1.0], [1.0, 0.0]], "regenerate": false },
+            "generated": { "regenerate": true },
+        }));
+        assert_eq!(serde_json::from_value::<Vectors>(serde_json::to_value(&vectors).unwrap()).unwrap(), vectors);
+
+        let vectors: Vectors = serde_json::from_value(serde_json::json!({
+            "default": [0.5, 1.0],
+            "other": null,
+        })).unwrap();
+        assert_eq!(vectors.get("default").unwrap().embeddings, Some(Embeddings::Single(vec![0.5, 1.0])));
+        assert_eq!(vectors.get("other").unwrap(), &EmbedderVectors { embeddings: None, regenerate: false });
+    }
+
+    #[async_test]
+    async fn test_index_for() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let products = client.index_for::<Product>();
+
+        let product = Product { reference: 4, name: String::from("chair") };
+        let task = products.add(&[product]).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        let product = products.get(4).await.unwrap();
+        let results = products.search("chair").await.unwrap();
+        client.delete_index(Product::INDEX_UID).await.unwrap();
+
+        assert_eq!(product.name, "chair");
+        assert_eq!(results.hits.len(), 1);
+    }
 }
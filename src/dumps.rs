@@ -15,51 +15,25 @@
 //! # Example
 //!
 //! ```no_run
-//! # use meilisearch_sdk::{client::*, errors::*, dumps::*};
-//! # use futures_await_test::async_test;
-//! # use std::{thread::sleep, time::Duration};
+//! # use meilisearch_sdk::{client::*, errors::*, dumps::*, tasks::*};
 //! # futures::executor::block_on(async move {
 //! #
 //! let client = Client::new("http://localhost:7700", "masterKey");
 //!
 //! // Create a dump
-//! let dump_info = client.create_dump().await.unwrap();
-//! assert!(matches!(dump_info.status, DumpStatus::InProgress));
+//! let task_info = client.create_dump().await.unwrap();
+//! assert_eq!(task_info.task_type, TaskType::DumpCreation);
 //!
 //! // Wait for MeiliSearch to proceed
-//! sleep(Duration::from_secs(5));
+//! let task = task_info.wait_for_completion(&client, None, None).await.unwrap();
+//! assert_eq!(task.status, TaskStatus::Succeeded);
 //!
-//! // Check the status of the dump
-//! let dump_info = client.get_dump_status(&dump_info.uid).await.unwrap();
-//! assert!(matches!(dump_info.status, DumpStatus::Done));
+//! // The name of the dump file, without the `.dump` extension
+//! let dump_uid = task.dump_uid().unwrap();
 //! # });
 //! ```
 
-use crate::{client::Client, errors::Error, request::*};
-use serde::Deserialize;
-
-/// The status of a dump.\
-/// Contained in [`DumpInfo`].
-#[derive(Debug, Deserialize, Clone, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum DumpStatus {
-    /// Dump creation is in progress.
-    Done,
-    /// Dump creation is in progress.
-    InProgress,
-    /// An error occured during dump process, and the task was aborted.
-    Failed,
-}
-
-/// Limited informations about a dump.\
-/// Can be obtained with [create_dump](Client::create_dump) and [get_dump_status](Client::get_dump_status) methods.
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct DumpInfo {
-    pub uid: String,
-    pub status: DumpStatus,
-    pub error: Option<serde_json::Value>,
-}
+use crate::{client::Client, errors::Error, request::*, tasks::TaskInfo};
 
 /// Dump related methods.\
 /// See the [dumps](crate::dumps) module.
@@ -68,91 +42,52 @@ impl Client {
     /// Once the process is complete, a dump is created in the [dumps directory](https://docs.meilisearch.com/reference/features/configuration.html#dumps-destination).
     /// If the dumps directory does not exist yet, it will be created.
     ///
+    /// The dump is created by a [task](crate::tasks::Task), whose [`dump_uid`](crate::tasks::Task::dump_uid) is known once it succeeded.
+    ///
     /// # Example
     ///
     /// ```no_run
     /// # use meilisearch_sdk::{client::*, errors::*, dumps::*};
-    /// # use futures_await_test::async_test;
-    /// # use std::{thread::sleep, time::Duration};
     /// # futures::executor::block_on(async move {
     /// #
     /// # let client = Client::new("http://localhost:7700", "masterKey");
     /// #
-    /// let dump_info = client.create_dump().await.unwrap();
-    /// assert!(matches!(dump_info.status, DumpStatus::InProgress));
+    /// let task_info = client.create_dump().await.unwrap();
+    /// let task = task_info.wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn create_dump(&self) -> Result<DumpInfo, Error> {
-        request::<(), DumpInfo>(
+    pub async fn create_dump(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!("{}/dumps", self.host),
-            &self.api_key,
+            &self.config,
             Method::Post(()),
             202,
         )
         .await
     }
-
-    /// Get the status of a dump creation process using [the uid](DumpInfo::uid) returned after calling the [dump creation method](Client::create_dump).
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// # use meilisearch_sdk::{client::*, errors::*, dumps::*};
-    /// # use futures_await_test::async_test;
-    /// # use std::{thread::sleep, time::Duration};
-    /// # futures::executor::block_on(async move {
-    /// #
-    /// # let client = Client::new("http://localhost:7700", "masterKey");
-    /// # let dump_info = client.create_dump().await.unwrap();
-    /// # sleep(Duration::from_secs(5));
-    /// #
-    /// let dump_info = client.get_dump_status(&dump_info.uid).await.unwrap();
-    /// # });
-    /// ```
-    pub async fn get_dump_status(&self, dump_uid: impl AsRef<str>) -> Result<DumpInfo, Error> {
-        request::<(), DumpInfo>(
-            &format!("{}/dumps/{}/status", self.host, dump_uid.as_ref()),
-            &self.api_key,
-            Method::Get,
-            200,
-        )
-        .await
-    }
 }
 
 /// Alias for [create_dump](Client::create_dump).
-pub async fn create_dump(client: &Client) -> Result<DumpInfo, Error> {
+pub async fn create_dump(client: &Client) -> Result<TaskInfo, Error> {
     client.create_dump().await
 }
 
-/// Alias for [get_dump_status](Client::get_dump_status).
-pub async fn get_dump_status(
-    client: &Client,
-    dump_uid: impl AsRef<str>,
-) -> Result<DumpInfo, Error> {
-    client.get_dump_status(dump_uid).await
-}
-
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::client::*;
+    use crate::{client::*, tasks::*};
     use futures_await_test::async_test;
-    use std::{thread::sleep, time::Duration};
 
     #[async_test]
     async fn test_dumps() {
         let client = Client::new("http://localhost:7700", "masterKey");
 
         // Create a dump
-        let dump_info = client.create_dump().await.unwrap();
-        assert!(matches!(dump_info.status, DumpStatus::InProgress));
-
-        // Wait for Meilisearch to do the dump
-        sleep(Duration::from_secs(5));
+        let task_info = client.create_dump().await.unwrap();
+        assert_eq!(task_info.task_type, TaskType::DumpCreation);
 
         // Assert that the dump was successful
-        let new_dump_info = client.get_dump_status(&dump_info.uid).await.unwrap();
-        assert!(matches!(new_dump_info.status, DumpStatus::Done));
+        let task = task_info.wait_for_completion(&client, None, Some(std::time::Duration::from_secs(60))).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        assert!(task.dump_uid().is_some());
     }
 }
@@ -1,25 +1,25 @@
+use crate::progress::TaskHandle;
+use serde::Deserialize;
+
 /// An enum representing the errors that can occur.
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum Error {
-    MeiliSearchError {
-        /// The human readable error message
-        message: String,
-        /// The error code of the error.  Officially documented at
-        /// https://docs.meilisearch.com/errors.
-        error_code: ErrorCode,
-        /// The type of error (invalid request, internal error, or authentication
-        /// error)
-        error_type: ErrorType,
-        /// A link to the MeiliSearch documentation for an error.
-        error_link: String,
-    },
+    /// MeiliSearch returned an error, see [`MeilisearchError`].
+    Meilisearch(MeilisearchError),
 
     /// There is no MeiliSearch server listening on the [specified host]
     /// (../client/struct.Client.html#method.new).
     UnreachableServer,
     /// The MeiliSearch server returned invalid JSON for a request.
     ParseError(serde_json::Error),
+    /// The server, or a proxy in front of it, returned an unexpected status with a body that isn't a MeiliSearch error.
+    UnexpectedResponse {
+        /// The HTTP status of the response
+        status: u16,
+        /// The body of the response
+        body: String,
+    },
     /// This Meilisearch sdk generated an invalid request (which was not sent).
     /// It probably comes from an invalid API key resulting in an invalid HTTP header.
     InvalidRequest,
@@ -30,6 +30,130 @@ pub enum Error {
     /// The http client encountered an error.
     #[cfg(target_arch = "wasm32")]
     HttpError(String),
+    /// Reading or writing a file failed.
+    IoError(std::io::Error),
+    /// An operation didn't complete before the timeout.
+    Timeout,
+    /// The request was cancelled with a [`CancellationToken`](../client/struct.CancellationToken.html).
+    Cancelled,
+    /// Some documents were rejected by their [`validate`](../document/trait.Document.html#method.validate) method.
+    /// None of the documents were sent.
+    InvalidDocuments(Vec<InvalidDocument>),
+}
+
+/// An error returned by MeiliSearch.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawMeilisearchError")]
+pub struct MeilisearchError {
+    /// The human readable error message
+    pub message: String,
+    /// The error code of the error. Officially documented at
+    /// https://docs.meilisearch.com/errors.
+    pub code: ErrorCode,
+    /// The type of error (invalid request, internal error, or authentication
+    /// error)
+    pub error_type: ErrorType,
+    /// A link to the MeiliSearch documentation for the error
+    pub link: String,
+}
+
+/// The body of an error response. Its fields were prefixed with `error` before MeiliSearch v0.25.
+#[derive(Deserialize)]
+struct RawMeilisearchError {
+    message: String,
+    #[serde(alias = "errorCode")]
+    code: String,
+    #[serde(default, rename = "type", alias = "errorType")]
+    error_type: Option<String>,
+    #[serde(default, alias = "errorLink")]
+    link: String,
+}
+
+impl From<RawMeilisearchError> for MeilisearchError {
+    fn from(raw: RawMeilisearchError) -> MeilisearchError {
+        MeilisearchError {
+            message: raw.message,
+            code: ErrorCode::parse(&raw.code),
+            // If the response doesn't contain an error type, the error type
+            // is assumed to be an internal error.
+            error_type: raw.error_type.as_deref().and_then(ErrorType::parse).unwrap_or(ErrorType::Internal),
+            link: raw.link,
+        }
+    }
+}
+
+/// The error of a failed [task](../tasks/struct.Task.html).
+impl From<crate::tasks::TaskError> for MeilisearchError {
+    fn from(error: crate::tasks::TaskError) -> MeilisearchError {
+        MeilisearchError {
+            message: error.message,
+            code: ErrorCode::parse(&error.code),
+            error_type: ErrorType::parse(&error.error_type).unwrap_or(ErrorType::Internal),
+            link: error.link,
+        }
+    }
+}
+
+impl std::fmt::Display for MeilisearchError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(fmt, "Meilisearch {}: {}: {}. {}", self.error_type.as_str(), self.code, self.message, self.link)
+    }
+}
+
+impl std::error::Error for MeilisearchError {}
+
+/// A document rejected by its [`validate`](../document/trait.Document.html#method.validate) method.
+#[derive(Debug, Clone)]
+pub struct InvalidDocument {
+    /// The position of the document in the list of documents to send
+    pub position: usize,
+    /// The primary key of the document
+    pub uid: String,
+    /// The reason returned by the validation
+    pub reason: String,
+}
+
+impl std::fmt::Display for InvalidDocument {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(fmt, "document {} (at position {}): {}", self.uid, self.position, self.reason)
+    }
+}
+
+/// The error of the methods sending documents in several batches, such as [`Index::add_documents_in_batches`](../indexes/struct.Index.html#method.add_documents_in_batches).
+///
+/// The batches after the failing one are not sent, but the ones enqueued before are not canceled,
+/// so the handles of their tasks are returned along with the error.
+/// It converts into its [`Error`], so that it can be propagated with `?`.
+#[derive(Debug)]
+pub struct BatchError {
+    /// The handles of the tasks of the batches enqueued before the error, in the order of the batches
+    pub handles: Vec<TaskHandle>,
+    /// Why the batch failed
+    pub error: Error,
+}
+
+impl std::fmt::Display for BatchError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(fmt, "{} (after enqueuing {} batches)", self.error, self.handles.len())
+    }
+}
+
+impl std::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<Error> for BatchError {
+    fn from(error: Error) -> BatchError {
+        BatchError { handles: Vec::new(), error }
+    }
+}
+
+impl From<BatchError> for Error {
+    fn from(error: BatchError) -> Error {
+        error.error
+    }
 }
 
 /// The type of error that was encountered.
@@ -151,9 +275,10 @@ impl ErrorType {
     /// returned.
     pub fn parse(input: &str) -> Option<Self> {
         match input {
-            "invalid_request_error" => Some(ErrorType::InvalidRequest),
-            "internal_error" => Some(ErrorType::Internal),
-            "authentication_error" => Some(ErrorType::Authentication),
+            // The `_error` suffixes were removed in MeiliSearch v0.25
+            "invalid_request_error" | "invalid_request" => Some(ErrorType::InvalidRequest),
+            "internal_error" | "internal" => Some(ErrorType::Internal),
+            "authentication_error" | "auth" => Some(ErrorType::Authentication),
             _ => None,
         }
     }
@@ -246,67 +371,31 @@ impl std::fmt::Display for ErrorCode {
 impl std::fmt::Display for Error {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         match self {
-            Error::MeiliSearchError {
-                message,
-                error_code,
-                error_type,
-                error_link,
-            } => write!(
-                fmt,
-                "Meilisearch {}: {}: {}. {}",
-                error_type.as_str(),
-                error_code,
-                message,
-                error_link,
-            ),
+            Error::Meilisearch(error) => write!(fmt, "{}", error),
             Error::UnreachableServer => write!(fmt, "The MeiliSearch server can't be reached."),
             Error::InvalidRequest => write!(fmt, "Unable to generate a valid HTTP request. It probably comes from an invalid API key."),
             Error::ParseError(e) => write!(fmt, "Error parsing response JSON: {}", e),
-            Error::HttpError(e) => write!(fmt, "HTTP request failed: {}", e)
+            Error::UnexpectedResponse { status, body } => write!(fmt, "Unexpected response with status {}: {}", status, body),
+            Error::HttpError(e) => write!(fmt, "HTTP request failed: {}", e),
+            Error::IoError(e) => write!(fmt, "I/O error: {}", e),
+            Error::Timeout => write!(fmt, "The operation didn't complete before the timeout."),
+            Error::Cancelled => write!(fmt, "The request was cancelled."),
+            Error::InvalidDocuments(documents) => {
+                write!(fmt, "{} invalid documents were not sent", documents.len())?;
+                for (i, document) in documents.iter().enumerate() {
+                    write!(fmt, "{} {}", if i == 0 { ":" } else { ";" }, document)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 impl std::error::Error for Error {}
 
-impl From<&serde_json::Value> for Error {
-    fn from(json: &serde_json::Value) -> Error {
-
-        let message = json
-            .get("message")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| json.to_string());
-
-        let error_link = json
-            .get("errorLink")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(String::new);
-
-        let error_type = json
-            .get("errorType")
-            .and_then(|v| v.as_str())
-            .and_then(|s| ErrorType::parse(s))
-            .unwrap_or(ErrorType::Internal);
-
-        // If the response doesn't contain an errorType field, the error type
-        // is assumed to be an internal error.
-
-        let error_code = json
-            .get("errorCode")
-            .and_then(|v| v.as_str())
-            .map(|s| ErrorCode::parse(s))
-            .unwrap_or_else(|| {
-                ErrorCode::Unknown(UnknownErrorCode(String::from("missing errorCode")))
-            });
-
-        Error::MeiliSearchError {
-            message,
-            error_code,
-            error_type,
-            error_link,
-        }
+impl From<MeilisearchError> for Error {
+    fn from(error: MeilisearchError) -> Error {
+        Error::Meilisearch(error)
     }
 }
 
@@ -315,6 +404,8 @@ impl From<isahc::Error> for Error {
     fn from(error: isahc::Error) -> Error {
         if error.kind() == isahc::error::ErrorKind::ConnectionFailed {
             Error::UnreachableServer
+        } else if error.kind() == isahc::error::ErrorKind::Timeout {
+            Error::Timeout
         } else {
             Error::HttpError(error)
         }
@@ -0,0 +1,155 @@
+use std::fmt::{self, Display};
+
+/// Derive the [`Fields`](trait.Fields.html) trait.
+/// Requires the `derive` feature.
+#[cfg(feature = "derive")]
+pub use meilisearch_sdk_macros::Fields;
+
+/// The name of an attribute of a document, as it is stored in MeiliSearch.
+///
+/// Fields can be used anywhere an attribute name is expected: in [filter expressions](../filter/index.html), sort rules, facets...
+/// They are usually obtained from a [`Fields`] implementation, instead of typo-prone string literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Field(&'static str);
+
+impl Field {
+    /// Create a field from the name of an attribute.
+    pub const fn new(name: &'static str) -> Field {
+        Field(name)
+    }
+
+    /// The name of the attribute.
+    pub const fn name(&self) -> &'static str {
+        self.0
+    }
+
+    /// A sort rule sorting documents by this attribute, in ascending order.
+    pub fn asc(&self) -> String {
+        format!("{}:asc", self.0)
+    }
+
+    /// A sort rule sorting documents by this attribute, in descending order.
+    pub fn desc(&self) -> String {
+        format!("{}:desc", self.0)
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl AsRef<str> for Field {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl From<Field> for &'static str {
+    fn from(field: Field) -> &'static str {
+        field.0
+    }
+}
+
+impl From<Field> for String {
+    fn from(field: Field) -> String {
+        field.0.to_string()
+    }
+}
+
+/// Documents whose attribute names are known at compile time.
+///
+/// With the `derive` feature, this trait can be derived.
+/// The attribute names are the ones serde uses, so `#[serde(rename)]` and `#[serde(rename_all)]` are respected.
+/// Fields marked with `#[serde(skip)]`, `#[serde(skip_serializing)]` or `#[serde(flatten)]` are left out.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use meilisearch_sdk::{fields::Fields, filter};
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize, Fields)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Movie {
+///     id: usize,
+///     title: String,
+///     release_date: i64,
+///     #[serde(rename = "genres")]
+///     kinds: Vec<String>,
+/// }
+///
+/// let fields = Movie::fields();
+/// assert_eq!(fields.release_date.name(), "releaseDate");
+/// assert_eq!(fields.kinds.name(), "genres");
+///
+/// let filter = filter::eq(fields.kinds, "Horror").and(filter::gt(fields.release_date, 946681200));
+/// assert_eq!(filter.to_string(), r#"genres = "Horror" AND releaseDate > 946681200"#);
+/// let sort = fields.release_date.desc();
+/// assert_eq!(sort, "releaseDate:desc");
+/// # }
+/// ```
+pub trait Fields {
+    /// A struct with one [`Field`] per attribute of the document.
+    type Fields;
+
+    /// Get the names of the attributes of the document.
+    fn fields() -> Self::Fields;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, meilisearch_sdk_macros::Fields)]
+    #[serde(rename_all = "camelCase")]
+    struct Movie {
+        id: usize,
+        release_date: i64,
+        #[serde(rename = "genres")]
+        kinds: Vec<String>,
+        #[serde(rename(serialize = "name", deserialize = "title"))]
+        title: String,
+        #[serde(skip)]
+        cached_score: Option<f64>,
+        r#type: String,
+    }
+
+    #[test]
+    fn test_derive_fields() {
+        let fields = Movie::fields();
+        assert_eq!(fields.id.name(), "id");
+        assert_eq!(fields.release_date.name(), "releaseDate");
+        assert_eq!(fields.kinds.name(), "genres");
+        assert_eq!(fields.title.name(), "name");
+        assert_eq!(fields.r#type.name(), "type");
+
+        let movie = Movie {
+            id: 1,
+            release_date: 0,
+            kinds: Vec::new(),
+            title: String::new(),
+            cached_score: None,
+            r#type: String::new(),
+        };
+        let value = serde_json::to_value(&movie).unwrap();
+        let object = value.as_object().unwrap();
+        assert_eq!(object.len(), 5);
+        for field in &[fields.id, fields.release_date, fields.kinds, fields.title, fields.r#type] {
+            assert!(object.contains_key(field.name()));
+        }
+        assert!(movie.cached_score.is_none());
+    }
+
+    #[test]
+    fn test_field() {
+        let field = Field::new("release_date");
+        assert_eq!(field.to_string(), "release_date");
+        assert_eq!(field.asc(), "release_date:asc");
+        assert_eq!(field.desc(), "release_date:desc");
+        assert_eq!(String::from(field), "release_date");
+    }
+}
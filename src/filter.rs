@@ -0,0 +1,527 @@
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+/// A number that can't be written in a filter, because it is NaN or infinite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NonFiniteNumber(pub f64);
+
+impl Display for NonFiniteNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "filter numbers must be finite, got {}", self.0)
+    }
+}
+
+impl std::error::Error for NonFiniteNumber {}
+
+/// Check that all the numbers are finite, returning the first one that isn't.
+pub(crate) fn check_finite(numbers: &[f64]) -> Result<(), NonFiniteNumber> {
+    match numbers.iter().find(|number| !number.is_finite()) {
+        Some(&number) => Err(NonFiniteNumber(number)),
+        None => Ok(()),
+    }
+}
+
+/// A value compared to an attribute in a filter expression.
+///
+/// Strings are quoted and escaped, integers and booleans are written as is.
+/// Floats are converted with [`TryFrom`], since NaN and infinite floats can't be written in a filter.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::filter::*;
+/// # use std::convert::TryFrom;
+/// let price = FilterValue::try_from(9.5).unwrap();
+/// assert_eq!(lt("price", price).to_string(), "price < 9.5");
+///
+/// assert!(FilterValue::try_from(f64::NAN).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterValue(String);
+
+impl Display for FilterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> FilterValue {
+        FilterValue(quote(value))
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> FilterValue {
+        FilterValue(quote(&value))
+    }
+}
+
+impl From<&String> for FilterValue {
+    fn from(value: &String) -> FilterValue {
+        FilterValue(quote(value))
+    }
+}
+
+macro_rules! impl_from_display {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for FilterValue {
+                fn from(value: $t) -> FilterValue {
+                    FilterValue(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_display!(bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_try_from_float {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<$t> for FilterValue {
+                type Error = NonFiniteNumber;
+
+                fn try_from(value: $t) -> Result<FilterValue, NonFiniteNumber> {
+                    if value.is_finite() {
+                        Ok(FilterValue(value.to_string()))
+                    } else {
+                        Err(NonFiniteNumber(value.into()))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_float!(f32, f64);
+
+/// A comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `=`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `>`
+    GreaterThan,
+    /// `>=`
+    GreaterThanOrEqual,
+    /// `<`
+    LowerThan,
+    /// `<=`
+    LowerThanOrEqual,
+}
+
+impl Operator {
+    /// Converts the operator to its representation in the filter syntax.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Equal => "=",
+            Operator::NotEqual => "!=",
+            Operator::GreaterThan => ">",
+            Operator::GreaterThanOrEqual => ">=",
+            Operator::LowerThan => "<",
+            Operator::LowerThanOrEqual => "<=",
+        }
+    }
+}
+
+/// A `_geoRadius` filter, selecting the documents whose `_geo` field is within a circle.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::filter::*;
+/// let radius = GeoRadius::new(45.472735, 9.184019, 2000.0).unwrap();
+/// assert_eq!(radius.to_string(), "_geoRadius(45.472735, 9.184019, 2000)");
+///
+/// // Geo filters can be combined with other expressions
+/// let expr = Expr::from(radius).and(eq("type", "pizza"));
+/// assert_eq!(expr.to_string(), r#"_geoRadius(45.472735, 9.184019, 2000) AND type = "pizza""#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoRadius {
+    lat: f64,
+    lng: f64,
+    distance_m: f64,
+}
+
+impl GeoRadius {
+    /// Create a circle from the latitude and longitude of its center, and its radius in meters.
+    /// Fails if one of them is NaN or infinite.
+    pub fn new(lat: f64, lng: f64, distance_m: f64) -> Result<GeoRadius, NonFiniteNumber> {
+        check_finite(&[lat, lng, distance_m])?;
+        Ok(GeoRadius { lat, lng, distance_m })
+    }
+
+    /// Latitude of the center of the circle
+    pub fn lat(&self) -> f64 {
+        self.lat
+    }
+
+    /// Longitude of the center of the circle
+    pub fn lng(&self) -> f64 {
+        self.lng
+    }
+
+    /// Radius of the circle, in meters
+    pub fn distance_m(&self) -> f64 {
+        self.distance_m
+    }
+}
+
+impl Display for GeoRadius {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "_geoRadius({}, {}, {})", self.lat, self.lng, self.distance_m)
+    }
+}
+
+/// A `_geoBoundingBox` filter, selecting the documents whose `_geo` field is within a rectangle.
+///
+/// Corners are `(lat, lng)` pairs.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::filter::*;
+/// let bounding_box = GeoBoundingBox::new((45.494181, 9.214024), (45.449484, 9.179175)).unwrap();
+/// assert_eq!(bounding_box.to_string(), "_geoBoundingBox([45.494181, 9.214024], [45.449484, 9.179175])");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoBoundingBox {
+    top_right: (f64, f64),
+    bottom_left: (f64, f64),
+}
+
+impl GeoBoundingBox {
+    /// Create a rectangle from its top right and bottom left corners.
+    /// Fails if a coordinate is NaN or infinite.
+    pub fn new(top_right: (f64, f64), bottom_left: (f64, f64)) -> Result<GeoBoundingBox, NonFiniteNumber> {
+        check_finite(&[top_right.0, top_right.1, bottom_left.0, bottom_left.1])?;
+        Ok(GeoBoundingBox { top_right, bottom_left })
+    }
+
+    /// Latitude and longitude of the top right corner
+    pub fn top_right(&self) -> (f64, f64) {
+        self.top_right
+    }
+
+    /// Latitude and longitude of the bottom left corner
+    pub fn bottom_left(&self) -> (f64, f64) {
+        self.bottom_left
+    }
+}
+
+impl Display for GeoBoundingBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "_geoBoundingBox([{}, {}], [{}, {}])",
+            self.top_right.0, self.top_right.1, self.bottom_left.0, self.bottom_left.1
+        )
+    }
+}
+
+/// A filter expression.
+///
+/// Build expressions with the functions of this module and combine them with [and](Expr::and), [or](Expr::or) and [not].
+/// The expression is rendered to the [filter syntax](https://docs.meilisearch.com/reference/features/filtering.html) with its `Display` implementation.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::filter::*;
+/// let expr = eq("genre", "sci-fi").and(lt("price", 10).or(not(exists("price"))));
+///
+/// assert_eq!(expr.to_string(), r#"genre = "sci-fi" AND (price < 10 OR NOT price EXISTS)"#);
+/// ```
+///
+/// The rendered string can then be used in a [query](crate::search::Query):
+///
+/// ```
+/// # use meilisearch_sdk::{client::Client, filter::*};
+/// # let client = Client::new("http://localhost:7700", "masterKey");
+/// # let index = client.assume_index("does not matter");
+/// let filter = eq("director", "Jordan \"JP\" Peele").to_string();
+/// let query = index.search()
+///     .with_filters(&filter)
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// `attribute <operator> value`
+    Comparison {
+        attribute: String,
+        operator: Operator,
+        value: FilterValue,
+    },
+    /// `attribute IN [values]`
+    In {
+        attribute: String,
+        values: Vec<FilterValue>,
+    },
+    /// `attribute EXISTS`
+    Exists(String),
+    /// `attribute IS EMPTY`
+    IsEmpty(String),
+    /// `_geoRadius(lat, lng, distance)`
+    GeoRadius(GeoRadius),
+    /// `_geoBoundingBox([lat, lng], [lat, lng])`
+    GeoBoundingBox(GeoBoundingBox),
+    /// `NOT expr`
+    Not(Box<Expr>),
+    /// `left AND right`
+    And(Box<Expr>, Box<Expr>),
+    /// `left OR right`
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Combines two expressions with `AND`.
+    pub fn and(self, other: Expr) -> Expr {
+        Expr::And(Box::new(self), Box::new(other))
+    }
+
+    /// Combines two expressions with `OR`.
+    pub fn or(self, other: Expr) -> Expr {
+        Expr::Or(Box::new(self), Box::new(other))
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Or(_, _) => 0,
+            Expr::And(_, _) => 1,
+            Expr::Not(_) => 2,
+            _ => 3,
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, min_precedence: u8) -> fmt::Result {
+        if self.precedence() < min_precedence {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Comparison {
+                attribute,
+                operator,
+                value,
+            } => write!(f, "{} {} {}", attribute, operator.as_str(), value),
+            Expr::In { attribute, values } => {
+                write!(f, "{} IN [", attribute)?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_str("]")
+            }
+            Expr::Exists(attribute) => write!(f, "{} EXISTS", attribute),
+            Expr::IsEmpty(attribute) => write!(f, "{} IS EMPTY", attribute),
+            Expr::GeoRadius(radius) => write!(f, "{}", radius),
+            Expr::GeoBoundingBox(bounding_box) => write!(f, "{}", bounding_box),
+            Expr::Not(expr) => {
+                f.write_str("NOT ")?;
+                expr.fmt_operand(f, 2)
+            }
+            Expr::And(left, right) => {
+                left.fmt_operand(f, 1)?;
+                f.write_str(" AND ")?;
+                right.fmt_operand(f, 1)
+            }
+            Expr::Or(left, right) => {
+                left.fmt_operand(f, 0)?;
+                f.write_str(" OR ")?;
+                right.fmt_operand(f, 0)
+            }
+        }
+    }
+}
+
+impl From<GeoRadius> for Expr {
+    fn from(radius: GeoRadius) -> Expr {
+        Expr::GeoRadius(radius)
+    }
+}
+
+impl From<GeoBoundingBox> for Expr {
+    fn from(bounding_box: GeoBoundingBox) -> Expr {
+        Expr::GeoBoundingBox(bounding_box)
+    }
+}
+
+impl std::ops::Not for Expr {
+    type Output = Expr;
+
+    fn not(self) -> Expr {
+        Expr::Not(Box::new(self))
+    }
+}
+
+fn comparison(attribute: impl Into<String>, operator: Operator, value: impl Into<FilterValue>) -> Expr {
+    Expr::Comparison {
+        attribute: attribute.into(),
+        operator,
+        value: value.into(),
+    }
+}
+
+/// `attribute = value`
+pub fn eq(attribute: impl Into<String>, value: impl Into<FilterValue>) -> Expr {
+    comparison(attribute, Operator::Equal, value)
+}
+
+/// `attribute != value`
+pub fn ne(attribute: impl Into<String>, value: impl Into<FilterValue>) -> Expr {
+    comparison(attribute, Operator::NotEqual, value)
+}
+
+/// `attribute > value`
+pub fn gt(attribute: impl Into<String>, value: impl Into<FilterValue>) -> Expr {
+    comparison(attribute, Operator::GreaterThan, value)
+}
+
+/// `attribute >= value`
+pub fn gte(attribute: impl Into<String>, value: impl Into<FilterValue>) -> Expr {
+    comparison(attribute, Operator::GreaterThanOrEqual, value)
+}
+
+/// `attribute < value`
+pub fn lt(attribute: impl Into<String>, value: impl Into<FilterValue>) -> Expr {
+    comparison(attribute, Operator::LowerThan, value)
+}
+
+/// `attribute <= value`
+pub fn lte(attribute: impl Into<String>, value: impl Into<FilterValue>) -> Expr {
+    comparison(attribute, Operator::LowerThanOrEqual, value)
+}
+
+/// `attribute IN [values]`
+pub fn in_<V: Into<FilterValue>>(attribute: impl Into<String>, values: impl IntoIterator<Item = V>) -> Expr {
+    Expr::In {
+        attribute: attribute.into(),
+        values: values.into_iter().map(Into::into).collect(),
+    }
+}
+
+/// `attribute EXISTS`
+pub fn exists(attribute: impl Into<String>) -> Expr {
+    Expr::Exists(attribute.into())
+}
+
+/// `attribute IS EMPTY`
+pub fn is_empty(attribute: impl Into<String>) -> Expr {
+    Expr::IsEmpty(attribute.into())
+}
+
+/// `_geoRadius(lat, lng, distance_m)`, failing if a number is NaN or infinite.
+pub fn geo_radius(lat: f64, lng: f64, distance_m: f64) -> Result<Expr, NonFiniteNumber> {
+    GeoRadius::new(lat, lng, distance_m).map(Expr::from)
+}
+
+/// `_geoBoundingBox([top_right], [bottom_left])`, failing if a coordinate is NaN or infinite.
+pub fn geo_bounding_box(top_right: (f64, f64), bottom_left: (f64, f64)) -> Result<Expr, NonFiniteNumber> {
+    GeoBoundingBox::new(top_right, bottom_left).map(Expr::from)
+}
+
+/// `NOT expr`
+pub fn not(expr: Expr) -> Expr {
+    !expr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comparisons() {
+        assert_eq!(eq("genre", "sci-fi").to_string(), r#"genre = "sci-fi""#);
+        assert_eq!(ne("genre", "sci-fi").to_string(), r#"genre != "sci-fi""#);
+        assert_eq!(gt("price", 10).to_string(), "price > 10");
+        assert_eq!(gte("price", FilterValue::try_from(9.5_f64).unwrap()).to_string(), "price >= 9.5");
+        assert_eq!(lt("price", 10u64).to_string(), "price < 10");
+        assert_eq!(lte("available", true).to_string(), "available <= true");
+        assert_eq!(in_("genre", ["horror", "comedy"]).to_string(), r#"genre IN ["horror", "comedy"]"#);
+        assert_eq!(exists("price").to_string(), "price EXISTS");
+        assert_eq!(is_empty("tags").to_string(), "tags IS EMPTY");
+    }
+
+    #[test]
+    fn test_float_values() {
+        assert_eq!(FilterValue::try_from(9.5_f64).unwrap().to_string(), "9.5");
+        assert_eq!(FilterValue::try_from(0.1_f32).unwrap().to_string(), "0.1");
+        assert!(FilterValue::try_from(f64::NAN).is_err());
+        assert_eq!(FilterValue::try_from(f32::NEG_INFINITY), Err(NonFiniteNumber(f64::NEG_INFINITY)));
+        assert_eq!(
+            FilterValue::try_from(f64::INFINITY).unwrap_err().to_string(),
+            "filter numbers must be finite, got inf"
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(
+            eq("director", r#"Jordan "JP" Peele \o/"#).to_string(),
+            r#"director = "Jordan \"JP\" Peele \\o/""#
+        );
+    }
+
+    #[test]
+    fn test_geo() {
+        assert_eq!(geo_radius(48.8566, 2.3522, 1500.5).unwrap().to_string(), "_geoRadius(48.8566, 2.3522, 1500.5)");
+        assert_eq!(geo_radius(-0.0000001, 0.0, 10.0).unwrap().to_string(), "_geoRadius(-0.0000001, 0, 10)");
+        assert_eq!(
+            not(geo_bounding_box((1.5, 2.0), (-1.0, -2.25)).unwrap()).to_string(),
+            "NOT _geoBoundingBox([1.5, 2], [-1, -2.25])"
+        );
+    }
+
+    #[test]
+    fn test_geo_non_finite() {
+        assert_eq!(geo_radius(48.8566, 2.3522, f64::INFINITY), Err(NonFiniteNumber(f64::INFINITY)));
+        assert!(GeoRadius::new(f64::NAN, 2.3522, 1500.0).is_err());
+        assert_eq!(
+            GeoBoundingBox::new((1.5, 2.0), (f64::NEG_INFINITY, -2.25)),
+            Err(NonFiniteNumber(f64::NEG_INFINITY))
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        let expr = eq("a", 1).or(eq("b", 2)).and(eq("c", 3));
+        assert_eq!(expr.to_string(), "(a = 1 OR b = 2) AND c = 3");
+
+        let expr = eq("a", 1).and(eq("b", 2)).or(eq("c", 3));
+        assert_eq!(expr.to_string(), "a = 1 AND b = 2 OR c = 3");
+
+        let expr = not(eq("a", 1).and(eq("b", 2)));
+        assert_eq!(expr.to_string(), "NOT (a = 1 AND b = 2)");
+
+        let expr = !exists("a");
+        assert_eq!(expr.to_string(), "NOT a EXISTS");
+    }
+}
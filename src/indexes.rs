@@ -1,15 +1,16 @@
 use crate::{
-    client::Client, document::*, errors::Error, errors::ErrorCode, progress::*, request::*, search::*, Rc,
+    client::{CancellationToken, Client, Compression}, document::*, errors::{BatchError, Error, ErrorCode, MeilisearchError}, progress::*, request::*, search::*, settings::Settings, tasks::{TaskInfo, TaskStatus}, Rc,
 };
+use futures::{stream, Stream, StreamExt, TryStreamExt};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::json;
-use std::{fmt::Display, collections::HashMap};
+use std::{fmt::Display, collections::HashMap, sync::atomic::{AtomicBool, Ordering}};
 
 #[derive(Deserialize, Debug)]
 #[allow(non_snake_case)]
 pub(crate) struct JsonIndex {
     uid: String,
-    primaryKey: Option<String>,
+    pub(crate) primaryKey: Option<String>,
     createdAt: String,
     updatedAt: String,
 }
@@ -19,7 +20,10 @@ impl JsonIndex {
         Index {
             uid: Rc::new(self.uid),
             host: Rc::clone(&client.host),
-            api_key: Rc::clone(&client.api_key)
+            config: Rc::clone(&client.config),
+            compression: client.compression,
+            max_payload_size: None,
+            upload_concurrency: 1,
         }
     }
 }
@@ -43,21 +47,39 @@ impl JsonIndex {
 pub struct Index {
     pub(crate) uid: Rc<String>,
     pub(crate) host: Rc<String>,
-    pub(crate) api_key: Rc<String>,
+    pub(crate) config: Rc<RequestConfig>,
+    pub(crate) compression: Option<Compression>,
+    pub(crate) max_payload_size: Option<usize>,
+    pub(crate) upload_concurrency: usize,
 }
 
 impl Index {
     /// Set the primary key of the index.
     ///
     /// If you prefer, you can use the method [set_primary_key](#method.set_primary_key), which is an alias.
-    pub async fn update(&self, primary_key: impl AsRef<str>) -> Result<(), Error> {
-        request::<serde_json::Value, JsonIndex>(
+    /// The primary key can't be changed once the index contains documents, in which case the task fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, tasks::TaskStatus};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies_update").await.unwrap();
+    ///
+    /// let task = movies.update("movie_id").await.unwrap().wait().await.unwrap();
+    /// assert_eq!(task.status, TaskStatus::Succeeded);
+    /// # client.delete_index("movies_update").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn update(&self, primary_key: impl AsRef<str>) -> Result<TaskHandle, Error> {
+        Ok(request::<serde_json::Value, TaskInfo>(
             &format!("{}/indexes/{}", self.host, self.uid),
-            &self.api_key,
-            Method::Put(json!({ "primaryKey": primary_key.as_ref() })),
-            200,
-        ).await?;
-        Ok(())
+            &self.config,
+            Method::Patch(json!({ "primaryKey": primary_key.as_ref() })),
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Delete the index.
@@ -68,23 +90,25 @@ impl Index {
     /// # use meilisearch_sdk::{client::*, indexes::*};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// # client.create_index("movies", None).await;
+    /// # client.get_or_create("movies").await.unwrap();
     ///
     /// // get the index named "movies" and delete it
     /// let movies = client.get_index("movies").await.unwrap();
-    /// movies.delete().await.unwrap();
+    /// movies.delete().await.unwrap().wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn delete(self) -> Result<(), Error> {
-        Ok(request::<(), ()>(
+    pub async fn delete(self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
             &format!("{}/indexes/{}", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Delete,
-            204,
-        ).await?)
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Delete the index if it exists.
+    /// Delete the index if it exists, and wait for the deletion to be processed.
+    /// Returns whether the index was deleted.
     ///
     /// # Example
     ///
@@ -92,7 +116,7 @@ impl Index {
     /// # use meilisearch_sdk::{client::*, indexes::*};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// client.create_index("movies", None).await;
+    /// client.get_or_create("movies").await.unwrap();
     ///
     /// // get the index named "movies" and delete it
     /// let movies = client.assume_index("movies");
@@ -108,15 +132,11 @@ impl Index {
     /// # });
     /// ```
     pub async fn delete_if_exists(self) -> Result<bool, Error> {
-        match self.delete().await {
-            Ok (_) => Ok(true),
-            Err (Error::MeiliSearchError {
-                message: _,
-                error_code: ErrorCode::IndexNotFound,
-                error_type: _,
-                error_link: _,
-            }) => Ok(false),
-            Err(error) => Err(error),
+        let task = self.delete().await?.wait().await?;
+        match task.error.map(MeilisearchError::from) {
+            Some(MeilisearchError { code: ErrorCode::IndexNotFound, .. }) => Ok(false),
+            Some(error) => Err(Error::Meilisearch(error)),
+            None => Ok(task.status == TaskStatus::Succeeded),
         }
     }
 
@@ -147,8 +167,7 @@ impl Index {
     /// let mut movies = client.get_or_create("movies").await.unwrap();
     ///
     /// // add some documents
-    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")},Movie{name:String::from("Unknown"), description:String::from("Unknown")}], Some("name")).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(1));
+    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")},Movie{name:String::from("Unknown"), description:String::from("Unknown")}], Some("name")).await.unwrap().wait().await.unwrap();
     ///
     /// let query = Query::new(&movies).with_query("Interstellar").with_limit(5).build();
     /// let results = movies.execute_query::<Movie>(&query).await.unwrap();
@@ -159,16 +178,173 @@ impl Index {
         &self,
         query: &Query<'_>,
     ) -> Result<SearchResults<T>, Error> {
-        Ok(request::<&Query, SearchResults<T>>(
-            &format!(
-                "{}/indexes/{}/search",
-                self.host,
-                self.uid
-            ),
-            &self.api_key,
-            Method::Post(query),
-            200,
-        ).await?)
+        self.execute_query_formatted::<T, T>(query).await
+    }
+
+    /// Search for documents matching a specific query in the index, deserializing the [formatted results](../search/struct.SearchResult.html#structfield.formatted_result) into `F`.\
+    /// See also the [execute_formatted method](../search/struct.Query.html#method.execute_formatted) of the query.
+    pub async fn execute_query_formatted<T: 'static + DeserializeOwned, F: 'static + DeserializeOwned>(
+        &self,
+        query: &Query<'_>,
+    ) -> Result<SearchResults<T, F>, Error> {
+        query.build_owned()?.execute_formatted::<T, F>().await
+    }
+
+    /// Get a stream of all the hits matching a query, issuing successive paginated searches until there are no more results.
+    ///
+    /// The pagination parameters of the query (`offset`, `limit`, `page` and `hits_per_page`) define where the stream starts and the size of the pages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::TryStreamExt;
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let query = movies.search().with_limit(100).build();
+    /// let mut hits = movies.search_all::<serde_json::Value>(&query);
+    /// while let Some(hit) = hits.try_next().await.unwrap() {
+    ///     println!("{}", hit.result);
+    /// }
+    /// # });
+    /// ```
+    pub fn search_all<T: 'static + DeserializeOwned>(
+        &self,
+        query: &Query<'_>,
+    ) -> impl Stream<Item = Result<SearchResult<T>, Error>> {
+        stream::try_unfold(Some(query.build_owned()), |query| async move {
+            let query = match query {
+                Some(query) => query?,
+                None => return Ok(None),
+            };
+            let results = query.execute::<T>().await?;
+            let next_query = if results.hits.is_empty() {
+                None
+            } else {
+                results.next_page_query().map(Ok)
+            };
+            let hits = stream::iter(results.hits.into_iter().map(Ok::<SearchResult<T>, Error>));
+            Ok::<_, Error>(Some((hits, next_query)))
+        })
+        .try_flatten()
+    }
+
+    /// Search for documents matching a specific query in the index, and get the response as raw JSON.
+    ///
+    /// This is useful to explore the responses of MeiliSearch without defining a document type,
+    /// or to debug a document type that doesn't match the documents of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let query = Query::new(&movies).with_query("Interstellar").build();
+    /// let response = movies.search_raw(&query).await.unwrap();
+    /// println!("{:#}", response["hits"]);
+    /// # });
+    /// ```
+    pub async fn search_raw(&self, query: &Query<'_>) -> Result<serde_json::Value, Error> {
+        query.build_owned()?.send::<serde_json::Value>().await
+    }
+
+    /// Search for documents matching a specific query in the index, keeping the hits as raw JSON.
+    ///
+    /// The hits are not parsed into documents, so they can be forwarded verbatim without a deserialization and serialization round-trip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let query = Query::new(&movies).with_query("Interstellar").build();
+    /// let results = movies.search_raw_hits(&query).await.unwrap();
+    /// for hit in results.hits {
+    ///     println!("{}", hit.get());
+    /// }
+    /// # });
+    /// ```
+    pub async fn search_raw_hits(&self, query: &Query<'_>) -> Result<RawSearchResults, Error> {
+        let mut metadata = query
+            .build_owned()?
+            .send::<std::collections::BTreeMap<String, Box<serde_json::value::RawValue>>>()
+            .await?;
+
+        let hits = match metadata.remove("hits") {
+            Some(hits) => serde_json::from_str(hits.get()).map_err(Error::ParseError)?,
+            None => Vec::new(),
+        };
+
+        Ok(RawSearchResults { hits, metadata })
+    }
+
+    /// Search for documents matching a specific query in the index, without failing if some hits can't be deserialized.
+    ///
+    /// The hits that were successfully deserialized are returned in the results,
+    /// and the other ones are reported with their position, their content and the deserialization error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    ///
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     name: String,
+    ///     description: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let query = Query::new(&movies).with_query("Interstellar").build();
+    /// let lossy = movies.search_lossy::<Movie>(&query).await.unwrap();
+    /// for error in lossy.errors {
+    ///     println!("hit {} is invalid: {} ({})", error.position, error.error, error.document);
+    /// }
+    /// # });
+    /// ```
+    pub async fn search_lossy<T: 'static + DeserializeOwned>(
+        &self,
+        query: &Query<'_>,
+    ) -> Result<LossySearchResults<T>, Error> {
+        let mut response = self.search_raw(query).await?;
+
+        let raw_hits = match response.get_mut("hits") {
+            Some(hits) => std::mem::replace(hits, serde_json::Value::Array(Vec::new())),
+            None => serde_json::Value::Null,
+        };
+        let raw_hits = match raw_hits {
+            serde_json::Value::Array(raw_hits) => raw_hits,
+            _ => Vec::new(),
+        };
+        let mut results: SearchResults<T> = serde_json::from_value(response).map_err(Error::ParseError)?;
+        results.origin = Some(query.build_owned()?);
+
+        let mut errors = Vec::new();
+        for (position, document) in raw_hits.into_iter().enumerate() {
+            match SearchResult::<T>::deserialize(&document) {
+                Ok(hit) => results.hits.push(hit),
+                Err(error) => errors.push(HitError {
+                    position,
+                    document,
+                    error,
+                }),
+            }
+        }
+
+        Ok(LossySearchResults { results, errors })
     }
 
     /// Search for documents matching a specific query in the index.\
@@ -199,8 +375,7 @@ impl Index {
     /// let mut movies = client.get_or_create("movies").await.unwrap();
     ///
     /// // add some documents
-    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")},Movie{name:String::from("Unknown"), description:String::from("Unknown")}], Some("name")).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(1));
+    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")},Movie{name:String::from("Unknown"), description:String::from("Unknown")}], Some("name")).await.unwrap().wait().await.unwrap();
     ///
     /// let results = movies.search()
     ///     .with_query("Interstellar")
@@ -242,11 +417,10 @@ impl Index {
     ///
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// # client.create_index("movies", None).await;
+    /// # client.get_or_create("movies").await.unwrap();
     /// let movies = client.get_index("movies").await.unwrap();
     /// # let mut movies = client.get_index("movies").await.unwrap();
-    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")}], Some("name")).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(1));
+    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")}], Some("name")).await.unwrap().wait().await.unwrap();
     /// #
     /// // retrieve a document (you have to put the document in the index before)
     /// let interstellar = movies.get_document::<Movie>(String::from("Interstellar")).await.unwrap();
@@ -261,9 +435,48 @@ impl Index {
         Ok(request::<(), T>(
             &format!(
                 "{}/indexes/{}/documents/{}",
-                self.host, self.uid, uid
+                self.host, self.uid, percent_encode(&uid.to_string())
+            ),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get only some fields of a document using its unique id.
+    ///
+    /// The document can be deserialized into any type, such as a reduced version of the [document](../document/trait.Document.html) type with only the selected fields,
+    /// which avoids transferring large fields that are not needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    /// struct MovieName {
+    ///    name: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies_get_document_with_fields").await.unwrap();
+    /// # let movie = serde_json::json!({ "id": 1, "name": "Interstellar", "description": "Interstellar chronicles the adventures of a group of explorers." });
+    /// # movies.update_documents(&[movie], Some("id")).await.unwrap().wait().await.unwrap();
+    ///
+    /// let interstellar = movies.get_document_with_fields::<MovieName>(1, &["name"]).await.unwrap();
+    /// assert_eq!(interstellar, MovieName { name: String::from("Interstellar") });
+    /// # client.delete_index("movies_get_document_with_fields").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_document_with_fields<T: 'static + DeserializeOwned>(&self, uid: impl Display, fields: &[&str]) -> Result<T, Error> {
+        Ok(request::<(), T>(
+            &format!(
+                "{}/indexes/{}/documents/{}?{}",
+                self.host, self.uid, percent_encode(&uid.to_string()), query_string(&json!({ "fields": fields }))
             ),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
@@ -300,12 +513,11 @@ impl Index {
     ///
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// # client.create_index("movies", None).await;
+    /// # client.get_or_create("movies").await.unwrap();
     /// let movie_index = client.get_index("movies").await.unwrap();
     /// # let mut movie_index = client.get_index("movies").await.unwrap();
     ///
-    /// # movie_index.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")}], Some("name")).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(1));
+    /// # movie_index.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")}], Some("name")).await.unwrap().wait().await.unwrap();
     /// #
     /// // retrieve movies (you have to put some movies in the index before)
     /// let movies = movie_index.get_documents::<Movie>(None, None, None).await.unwrap();
@@ -336,12 +548,94 @@ impl Index {
         }
         Ok(request::<(), Vec<T>>(
             &url,
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
     }
 
+    /// Get a page of the documents matching a [query](../document/struct.DocumentsQuery.html).
+    ///
+    /// Unlike [get_documents](#method.get_documents), the documents can be filtered, and they don't have to implement [Document](../document/trait.Document.html):
+    /// a struct with only some of the fields can be used together with [`DocumentsQuery::with_fields`](../document/struct.DocumentsQuery.html#method.with_fields).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    ///
+    /// # use meilisearch_sdk::{client::*, document::*, indexes::*};
+    /// #[derive(Deserialize, Debug)]
+    /// struct MovieTitle {
+    ///    title: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies_fetch_documents").await.unwrap();
+    ///
+    /// let mut query = DocumentsQuery::new(&movies);
+    /// query.with_fields(&["title"]).with_offset(20).with_limit(10);
+    /// let page = movies.fetch_documents::<MovieTitle>(&query).await.unwrap();
+    /// println!("{} of {} titles", page.results.len(), page.total);
+    /// # client.delete_index("movies_fetch_documents").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn fetch_documents<T: 'static + DeserializeOwned>(&self, query: &DocumentsQuery<'_>) -> Result<DocumentsResults<T>, Error> {
+        request::<&DocumentsQuery, DocumentsResults<T>>(
+            &format!("{}/indexes/{}/documents/fetch", self.host, self.uid),
+            &self.config,
+            Method::Post(query),
+            200,
+        ).await
+    }
+
+    /// Get a stream of all the documents of the index, fetched by pages of 1000 documents.
+    ///
+    /// This is useful to export or reindex all the documents, without handling the pagination.
+    /// The documents added or deleted while the stream is consumed may be missed or returned twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::TryStreamExt;
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let mut documents = movies.documents_stream::<serde_json::Value>();
+    /// while let Some(document) = documents.try_next().await.unwrap() {
+    ///     println!("{}", document);
+    /// }
+    /// # });
+    /// ```
+    pub fn documents_stream<T: 'static + DeserializeOwned>(&self) -> impl Stream<Item = Result<T, Error>> {
+        const PAGE_SIZE: usize = 1000;
+
+        stream::try_unfold(Some((self.clone(), 0)), |state| async move {
+            let (index, offset) = match state {
+                Some(state) => state,
+                None => return Ok(None),
+            };
+            let page = DocumentsQuery::new(&index)
+                .with_offset(offset)
+                .with_limit(PAGE_SIZE)
+                .execute::<T>()
+                .await?;
+            let next_offset = offset + page.results.len();
+            let next_state = if page.results.is_empty() || next_offset >= page.total {
+                None
+            } else {
+                Some((index, next_offset))
+            };
+            let documents = stream::iter(page.results.into_iter().map(Ok::<T, Error>));
+            Ok::<_, Error>(Some((documents, next_state)))
+        })
+        .try_flatten()
+    }
+
     /// Add a list of [documents](../document/trait.Document.html) or replace them if they already exist.
     ///
     /// If you send an already existing document (same id) the **whole existing document** will be overwritten by the new document.
@@ -351,14 +645,15 @@ impl Index {
     ///
     /// You can use the alias [add_documents](#method.add_documents) if you prefer.
     ///
+    /// The documents are first checked with their [validate](../document/trait.Document.html#method.validate) method.
+    /// If any of them is invalid, nothing is sent and an [`Error::InvalidDocuments`](../errors/enum.Error.html#variant.InvalidDocuments) listing them is returned.
+    ///
     /// # Example
     ///
     /// ```
     /// use serde::{Serialize, Deserialize};
     ///
     /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
-    /// # use std::thread::sleep;
-    /// # use std::time::Duration;
     /// #[derive(Serialize, Deserialize, Debug)]
     /// struct Movie {
     ///    name: String,
@@ -391,8 +686,7 @@ impl Index {
     ///         description: String::from("The true story of technical troubles that scuttle the Apollo 13 lunar mission in 1971, risking the lives of astronaut Jim Lovell and his crew, with the failed journey turning into a thrilling saga of heroism. Drifting more than 200,000 miles from Earth, the astronauts work furiously with the ground crew to avert tragedy.")
     ///     },
     /// ], Some("name")).await.unwrap();
-    /// sleep(Duration::from_secs(1)); // MeiliSearch may take some time to execute the request
-    /// # progress.get_status().await.unwrap();
+    /// progress.wait().await.unwrap(); // MeiliSearch may take some time to execute the request
     ///
     /// // retrieve movies (you have to put some movies in the index before)
     /// let movies = movie_index.get_documents::<Movie>(None, None, None).await.unwrap();
@@ -403,23 +697,24 @@ impl Index {
         &self,
         documents: &[T],
         primary_key: Option<&str>,
-    ) -> Result<Progress, Error> {
-        let url = if let Some(primary_key) = primary_key {
-            format!(
-                "{}/indexes/{}/documents?primaryKey={}",
-                self.host, self.uid, primary_key
-            )
-        } else {
-            format!("{}/indexes/{}/documents", self.host, self.uid)
-        };
+    ) -> Result<TaskHandle, Error> {
+        validate_documents(documents)?;
+        let url = format!(
+            "{}/indexes/{}/documents?{}",
+            self.host, self.uid, query_string(&PayloadFormat::Json.parameters(primary_key))
+        );
+        if self.compression.is_some() {
+            let payload = self.json_payload(documents)?;
+            return self.send_documents_payload(Method::Post(payload), PayloadFormat::Json.parameters(primary_key)).await;
+        }
         Ok(
-            request::<&[T], ProgressJson>(
+            request::<&[T], TaskInfo>(
                 &url,
-                &self.api_key,
+                &self.config,
                 Method::Post(documents),
                 202,
             ).await?
-            .into_progress(self),
+            .into_task_handle(&self.host, &self.config),
         )
     }
 
@@ -428,10 +723,61 @@ impl Index {
         &self,
         documents: &[T],
         primary_key: Option<&str>,
-    ) -> Result<Progress, Error> {
+    ) -> Result<TaskHandle, Error> {
         self.add_or_replace(documents, primary_key).await
     }
 
+    /// Add a list of documents in batches of `batch_size` documents (default: 1000), or replace them if they already exist.
+    ///
+    /// Each batch is sent in its own request and is processed as its own [task](../tasks/struct.Task.html), so that large lists of documents don't exceed the payload limit of the server.
+    /// A batch larger than the [maximum payload size](#method.with_max_payload_size) of the index, or rejected by the server as too large, is split in two halves which are sent instead.
+    /// The batches are enqueued in order, unless the [upload concurrency](#method.with_upload_concurrency) of the index is greater than one, and the handles of their tasks are returned in the order of the batches.
+    /// If a request fails, the following batches are not sent, and the batches enqueued before it are not canceled:
+    /// their handles are returned in the [`BatchError`](../errors/struct.BatchError.html).
+    /// The documents are all [validated](#method.add_or_replace) before the first batch is sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///    id: usize,
+    ///    name: String,
+    /// }
+    ///
+    /// impl Document for Movie {
+    ///    type UIDType = usize;
+    ///    fn get_uid(&self) -> &Self::UIDType {
+    ///        &self.id
+    ///    }
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies_add_documents_in_batches").await.unwrap();
+    ///
+    /// let movies: Vec<Movie> = (0..2500).map(|id| Movie { id, name: format!("Movie {}", id) }).collect();
+    /// let handles = movie_index.add_documents_in_batches(&movies, Some(1000), Some("id")).await.unwrap();
+    /// assert_eq!(handles.len(), 3);
+    /// for handle in handles {
+    ///     handle.wait().await.unwrap();
+    /// }
+    /// # client.delete_index("movies_add_documents_in_batches").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_documents_in_batches<T: Document>(
+        &self,
+        documents: &[T],
+        batch_size: Option<usize>,
+        primary_key: Option<&str>,
+    ) -> Result<Vec<TaskHandle>, BatchError> {
+        validate_documents(documents)?;
+        self.send_in_batches(documents, batch_size, primary_key, false).await
+    }
+
     /// Add a list of documents and update them if they already.
     ///
     /// If you send an already existing document (same id) the old document will be only partially updated according to the fields of the new document.
@@ -445,8 +791,6 @@ impl Index {
     /// use serde::{Serialize, Deserialize};
     ///
     /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
-    /// # use std::thread::sleep;
-    /// # use std::time::Duration;
     /// #[derive(Serialize, Deserialize, Debug)]
     /// struct Movie {
     ///    name: String,
@@ -479,8 +823,7 @@ impl Index {
     ///         description: String::from("The true story of technical troubles that scuttle the Apollo 13 lunar mission in 1971, risking the lives of astronaut Jim Lovell and his crew, with the failed journey turning into a thrilling saga of heroism. Drifting more than 200,000 miles from Earth, the astronauts work furiously with the ground crew to avert tragedy.")
     ///     },
     /// ], Some("name")).await.unwrap();
-    /// sleep(Duration::from_secs(1)); // MeiliSearch may take some time to execute the request
-    /// # progress.get_status().await.unwrap();
+    /// progress.wait().await.unwrap(); // MeiliSearch may take some time to execute the request
     ///
     /// // retrieve movies (you have to put some movies in the index before)
     /// let movies = movie_index.get_documents::<Movie>(None, None, None).await.unwrap();
@@ -491,82 +834,511 @@ impl Index {
         &self,
         documents: &[T],
         primary_key: Option<impl AsRef<str>>,
-    ) -> Result<Progress, Error> {
-        let url = if let Some(primary_key) = primary_key {
-            format!(
-                "{}/indexes/{}/documents?primaryKey={}",
-                self.host, self.uid, primary_key.as_ref()
-            )
-        } else {
-            format!("{}/indexes/{}/documents", self.host, self.uid)
-        };
-        Ok(
-            request::<&[T], ProgressJson>(&url, &self.api_key, Method::Put(documents), 202).await?
-                .into_progress(self),
-        )
+    ) -> Result<TaskHandle, Error> {
+        validate_documents(documents)?;
+        self.update_documents(documents, primary_key.as_ref().map(|primary_key| primary_key.as_ref())).await
     }
 
-    /// Delete all documents in the index.
+    /// Partially update a list of documents, or add them if they don't exist yet.
+    ///
+    /// Unlike [add_or_update](#method.add_or_update), the documents don't have to implement [Document](../document/trait.Document.html):
+    /// any serializable type can be sent, such as a struct with only the primary key and the fields to change, or a [`serde_json::Value`].
+    /// The fields missing from the sent documents are kept unchanged.
+    ///
+    /// Since the documents are partial, they are not [validated](../document/trait.Document.html#method.validate):
+    /// use [add_or_update](#method.add_or_update) to validate complete documents before sending them.
     ///
     /// # Example
     ///
     /// ```
-    /// # use serde::{Serialize, Deserialize};
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
-    /// #
-    /// # #[derive(Serialize, Deserialize, Debug)]
-    /// # struct Movie {
-    /// #    name: String,
-    /// #    description: String,
-    /// # }
-    /// #
-    /// # // that trait is used by the sdk when the primary key is needed
-    /// # impl Document for Movie {
-    /// #    type UIDType = String;
-    /// #    fn get_uid(&self) -> &Self::UIDType {
-    /// #        &self.name
-    /// #    }
-    /// # }
-    /// #
+    /// use serde::Serialize;
+    ///
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #[derive(Serialize, Debug)]
+    /// struct MoviePriceUpdate {
+    ///    id: usize,
+    ///    price: f32,
+    /// }
+    ///
     /// # futures::executor::block_on(async move {
-    /// #
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
-    ///
-    /// // add some documents
+    /// let movie_index = client.get_or_create("movies_update_documents").await.unwrap();
     ///
-    /// let progress = movie_index.delete_all_documents().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(1));
-    /// # progress.get_status().await.unwrap();
-    /// # let movies = movie_index.get_documents::<Movie>(None, None, None).await.unwrap();
-    /// # assert_eq!(movies.len(), 0);
+    /// let task = movie_index
+    ///     .update_documents(&[MoviePriceUpdate { id: 1, price: 9.99 }, MoviePriceUpdate { id: 2, price: 4.99 }], Some("id"))
+    ///     .await
+    ///     .unwrap();
+    /// task.wait().await.unwrap();
+    /// # client.delete_index("movies_update_documents").await.unwrap();
     /// # });
     /// ```
-    pub async fn delete_all_documents(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/documents", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
-            202,
-        ).await?
-        .into_progress(self))
+    pub async fn update_documents<T: Serialize + std::fmt::Debug>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskHandle, Error> {
+        let url = format!(
+            "{}/indexes/{}/documents?{}",
+            self.host, self.uid, query_string(&PayloadFormat::Json.parameters(primary_key))
+        );
+        if self.compression.is_some() {
+            let payload = self.json_payload(documents)?;
+            return self.send_documents_payload(Method::Put(payload), PayloadFormat::Json.parameters(primary_key)).await;
+        }
+        Ok(
+            request::<&[T], TaskInfo>(&url, &self.config, Method::Put(documents), 202).await?
+                .into_task_handle(&self.host, &self.config),
+        )
     }
 
-    /// Delete one document based on its unique id.
-    ///
-    /// # Example
+    /// Partially update a list of documents in batches of `batch_size` documents (default: 1000), or add them if they don't exist yet.
     ///
-    /// ```
-    /// # use serde::{Serialize, Deserialize};
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
-    /// #
-    /// # #[derive(Serialize, Deserialize, Debug)]
-    /// # struct Movie {
-    /// #    name: String,
-    /// #    description: String,
-    /// # }
-    /// #
-    /// # // that trait is used by the sdk when the primary key is needed
+    /// See [update_documents](#method.update_documents) for the update semantics, and [add_documents_in_batches](#method.add_documents_in_batches) for the batching.
+    /// Like with [update_documents](#method.update_documents), the partial documents are not [validated](../document/trait.Document.html#method.validate).
+    pub async fn update_documents_in_batches<T: Serialize + std::fmt::Debug>(
+        &self,
+        documents: &[T],
+        batch_size: Option<usize>,
+        primary_key: Option<&str>,
+    ) -> Result<Vec<TaskHandle>, BatchError> {
+        self.send_in_batches(documents, batch_size, primary_key, true).await
+    }
+
+    async fn send_in_batches<T: Serialize>(
+        &self,
+        documents: &[T],
+        batch_size: Option<usize>,
+        primary_key: Option<&str>,
+        update: bool,
+    ) -> Result<Vec<TaskHandle>, BatchError> {
+        // Once a batch fails, the batches that are not being sent yet are skipped,
+        // but the ones being sent are awaited so that the handles of their tasks aren't lost
+        let failed = AtomicBool::new(false);
+        let mut results = stream::iter(documents.chunks(batch_size.unwrap_or(1000).max(1)))
+            .map(|batch| {
+                let failed = &failed;
+                async move {
+                    if failed.load(Ordering::SeqCst) {
+                        return (Vec::new(), None);
+                    }
+                    let (handles, error) = self.send_batch(batch, primary_key, update).await;
+                    if error.is_some() {
+                        failed.store(true, Ordering::SeqCst);
+                    }
+                    (handles, error)
+                }
+            })
+            .buffered(self.upload_concurrency.max(1));
+
+        let mut handles = Vec::new();
+        let mut first_error = None;
+        while let Some((batch_handles, error)) = results.next().await {
+            handles.extend(batch_handles);
+            first_error = first_error.or(error);
+        }
+        match first_error {
+            Some(error) => Err(BatchError { handles, error }),
+            None => Ok(handles),
+        }
+    }
+
+    /// Send a batch of documents, split in as many requests as needed to fit the payload size limit.
+    /// Returns the handles of the requests sent before an error, if any.
+    async fn send_batch<T: Serialize>(
+        &self,
+        batch: &[T],
+        primary_key: Option<&str>,
+        update: bool,
+    ) -> (Vec<TaskHandle>, Option<Error>) {
+        fn split<'a, T>(batches: &mut Vec<&'a [T]>, batch: &'a [T]) {
+            let (first_half, second_half) = batch.split_at(batch.len() / 2);
+            batches.push(second_half);
+            batches.push(first_half);
+        }
+
+        let mut handles = Vec::new();
+        // The parts left to send, the next one being at the end
+        let mut batches: Vec<&[T]> = vec![batch];
+        while let Some(batch) = batches.pop() {
+            let payload = match self.json_payload(batch) {
+                Ok(payload) => payload,
+                Err(error) => return (handles, Some(error)),
+            };
+            let splittable = batch.len() > 1;
+            if splittable && self.max_payload_size.map_or(false, |max_payload_size| payload.data.len() > max_payload_size) {
+                split(&mut batches, batch);
+                continue;
+            }
+
+            let method = if update { Method::Put(payload) } else { Method::Post(payload) };
+            match self.send_documents_payload(method, PayloadFormat::Json.parameters(primary_key)).await {
+                Ok(handle) => handles.push(handle),
+                Err(Error::Meilisearch(MeilisearchError { code: ErrorCode::PayloadTooLarge, .. })) if splittable => {
+                    split(&mut batches, batch);
+                }
+                Err(error) => return (handles, Some(error)),
+            }
+        }
+        (handles, None)
+    }
+
+    /// Add documents encoded as [NDJSON](http://ndjson.org/) (one JSON document per line), or replace them if they already exist.
+    ///
+    /// The payload is sent as is, which avoids deserializing and reserializing large exports.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies_add_documents_ndjson").await.unwrap();
+    ///
+    /// let payload = r#"{ "id": 1, "name": "Interstellar" }
+    /// { "id": 2, "name": "Apollo13" }"#;
+    /// let task = movie_index.add_documents_ndjson(payload.as_bytes(), Some("id")).await.unwrap();
+    /// task.wait().await.unwrap();
+    /// # client.delete_index("movies_add_documents_ndjson").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_documents_ndjson(&self, payload: &[u8], primary_key: Option<&str>) -> Result<TaskHandle, Error> {
+        let format = PayloadFormat::Ndjson;
+        let payload = Payload { content_type: format.content_type(), data: payload.into(), compression: self.compression };
+        self.send_documents_payload(Method::Post(payload), format.parameters(primary_key)).await
+    }
+
+    /// Partially update documents encoded as [NDJSON](http://ndjson.org/), or add them if they don't exist yet.
+    ///
+    /// See [add_documents_ndjson](#method.add_documents_ndjson) for the format, and [update_documents](#method.update_documents) for the update semantics.
+    pub async fn update_documents_ndjson(&self, payload: &[u8], primary_key: Option<&str>) -> Result<TaskHandle, Error> {
+        let format = PayloadFormat::Ndjson;
+        let payload = Payload { content_type: format.content_type(), data: payload.into(), compression: self.compression };
+        self.send_documents_payload(Method::Put(payload), format.parameters(primary_key)).await
+    }
+
+    /// Add documents encoded as CSV, or replace them if they already exist.
+    ///
+    /// The first line is the header, naming the fields. By default, all the values are strings: a field can be typed with a suffix such as `id:number` or `available:boolean`.
+    /// The fields are separated by `csv_delimiter`, which must be an ASCII character (default: `,`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies_add_documents_csv").await.unwrap();
+    ///
+    /// let payload = "id:number;name\n1;Interstellar\n2;Apollo13\n";
+    /// let task = movie_index.add_documents_csv(payload.as_bytes(), Some(';'), Some("id")).await.unwrap();
+    /// task.wait().await.unwrap();
+    /// # client.delete_index("movies_add_documents_csv").await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_documents_csv(
+        &self,
+        payload: &[u8],
+        csv_delimiter: Option<char>,
+        primary_key: Option<&str>,
+    ) -> Result<TaskHandle, Error> {
+        let format = PayloadFormat::Csv { delimiter: csv_delimiter };
+        let payload = Payload { content_type: format.content_type(), data: payload.into(), compression: self.compression };
+        self.send_documents_payload(Method::Post(payload), format.parameters(primary_key)).await
+    }
+
+    /// Partially update documents encoded as CSV, or add them if they don't exist yet.
+    ///
+    /// See [add_documents_csv](#method.add_documents_csv) for the format, and [update_documents](#method.update_documents) for the update semantics.
+    pub async fn update_documents_csv(
+        &self,
+        payload: &[u8],
+        csv_delimiter: Option<char>,
+        primary_key: Option<&str>,
+    ) -> Result<TaskHandle, Error> {
+        let format = PayloadFormat::Csv { delimiter: csv_delimiter };
+        let payload = Payload { content_type: format.content_type(), data: payload.into(), compression: self.compression };
+        self.send_documents_payload(Method::Put(payload), format.parameters(primary_key)).await
+    }
+
+    /// Add documents read progressively from `reader`, or replace them if they already exist.
+    ///
+    /// The documents are sent while they are read, so even very large files are never held in memory.
+    /// They must be encoded in the given [format](enum.PayloadFormat.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies_add_documents_from_reader").await.unwrap();
+    ///
+    /// // Any AsyncRead works, such as an async file
+    /// let reader = futures::io::Cursor::new(b"{ \"id\": 1, \"name\": \"Interstellar\" }\n".to_vec());
+    /// let task = movie_index.add_documents_from_reader(reader, PayloadFormat::Ndjson, Some("id")).await.unwrap();
+    /// task.wait().await.unwrap();
+    /// # client.delete_index("movies_add_documents_from_reader").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn add_documents_from_reader(
+        &self,
+        reader: impl futures::io::AsyncRead + Send + Sync + 'static,
+        format: PayloadFormat,
+        primary_key: Option<&str>,
+    ) -> Result<TaskHandle, Error> {
+        let payload = StreamPayload {
+            content_type: format.content_type(),
+            body: isahc::AsyncBody::from_reader(reader),
+            compression: self.compression,
+        };
+        self.send_documents_stream(Method::Post(payload), format.parameters(primary_key)).await
+    }
+
+    /// Partially update documents read progressively from `reader`, or add them if they don't exist yet.
+    ///
+    /// See [add_documents_from_reader](#method.add_documents_from_reader) for the streaming, and [update_documents](#method.update_documents) for the update semantics.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn update_documents_from_reader(
+        &self,
+        reader: impl futures::io::AsyncRead + Send + Sync + 'static,
+        format: PayloadFormat,
+        primary_key: Option<&str>,
+    ) -> Result<TaskHandle, Error> {
+        let payload = StreamPayload {
+            content_type: format.content_type(),
+            body: isahc::AsyncBody::from_reader(reader),
+            compression: self.compression,
+        };
+        self.send_documents_stream(Method::Put(payload), format.parameters(primary_key)).await
+    }
+
+    /// Add documents from a stream of chunks of bytes, or replace them if they already exist.
+    ///
+    /// The chunks can be any bytes container, such as `Vec<u8>` or `bytes::Bytes`, and don't have to be aligned on documents.
+    /// See [add_documents_from_reader](#method.add_documents_from_reader).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies_add_documents_from_stream").await.unwrap();
+    ///
+    /// let chunks: Vec<std::io::Result<&'static str>> = vec![Ok("{ \"id\": 1, \"na"), Ok("me\": \"Interstellar\" }\n")];
+    /// let task = movie_index
+    ///     .add_documents_from_stream(futures::stream::iter(chunks), PayloadFormat::Ndjson, Some("id"))
+    ///     .await
+    ///     .unwrap();
+    /// task.wait().await.unwrap();
+    /// # client.delete_index("movies_add_documents_from_stream").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn add_documents_from_stream<B: AsRef<[u8]> + Send + Sync + 'static>(
+        &self,
+        stream: impl Stream<Item = std::io::Result<B>> + Unpin + Send + Sync + 'static,
+        format: PayloadFormat,
+        primary_key: Option<&str>,
+    ) -> Result<TaskHandle, Error> {
+        self.add_documents_from_reader(stream.into_async_read(), format, primary_key).await
+    }
+
+    /// Partially update documents from a stream of chunks of bytes, or add them if they don't exist yet.
+    ///
+    /// See [add_documents_from_stream](#method.add_documents_from_stream) and [update_documents](#method.update_documents).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn update_documents_from_stream<B: AsRef<[u8]> + Send + Sync + 'static>(
+        &self,
+        stream: impl Stream<Item = std::io::Result<B>> + Unpin + Send + Sync + 'static,
+        format: PayloadFormat,
+        primary_key: Option<&str>,
+    ) -> Result<TaskHandle, Error> {
+        self.update_documents_from_reader(stream.into_async_read(), format, primary_key).await
+    }
+
+    /// Get a copy of this index whose documents are compressed with `compression`, or not compressed with `None`.
+    ///
+    /// By default, an index uses the [compression of its client](../client/struct.Client.html#method.with_compression).
+    /// Since indexes are cheap to clone, this can be used for a single call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies_with_compression").await.unwrap();
+    ///
+    /// let payload = r#"{ "id": 1, "name": "Interstellar" }"#;
+    /// let task = movie_index
+    ///     .clone()
+    ///     .with_compression(Some(Compression::Gzip))
+    ///     .add_documents_ndjson(payload.as_bytes(), Some("id"))
+    ///     .await
+    ///     .unwrap();
+    /// task.wait().await.unwrap();
+    /// # client.delete_index("movies_with_compression").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn with_compression(self, compression: Option<Compression>) -> Index {
+        Index { compression, ..self }
+    }
+
+    /// Get a copy of this index whose batched document methods, such as [add_documents_in_batches](#method.add_documents_in_batches), never send more than `max_payload_size` bytes of JSON in a request, except for a single document larger than that.
+    ///
+    /// Without a maximum, the batches are only split when the server rejects them as too large (100MB by default, see the `--http-payload-size-limit` option of MeiliSearch).
+    pub fn with_max_payload_size(self, max_payload_size: Option<usize>) -> Index {
+        Index { max_payload_size, ..self }
+    }
+
+    /// Get a copy of this index whose batched document methods, such as [add_documents_in_batches](#method.add_documents_in_batches), send up to `concurrency` batches at the same time (default: 1).
+    ///
+    /// Sending batches concurrently keeps the network busy while the server enqueues the previous ones, but the batches may then be enqueued in any order:
+    /// if the same document appears in several batches, the version that is kept is not defined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use serde_json::json;
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies_with_upload_concurrency").await.unwrap().with_upload_concurrency(4);
+    ///
+    /// let movies: Vec<_> = (0..10_000).map(|id| json!({ "id": id, "name": format!("Movie {}", id) })).collect();
+    /// let handles = movie_index.update_documents_in_batches(&movies, Some(1000), Some("id")).await.unwrap();
+    /// assert_eq!(handles.len(), 10);
+    /// # for handle in handles {
+    /// #     handle.wait().await.unwrap();
+    /// # }
+    /// # client.delete_index("movies_with_upload_concurrency").await.unwrap();
+    /// # });
+    /// ```
+    pub fn with_upload_concurrency(self, concurrency: usize) -> Index {
+        Index { upload_concurrency: concurrency, ..self }
+    }
+
+    /// Get a copy of this index whose requests fail with [`Error::Timeout`](../errors/enum.Error.html#variant.Timeout) if their response isn't received within `timeout`,
+    /// instead of the [timeout of its client](../client/struct.Client.html#method.with_timeout).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use std::time::Duration;
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.assume_index("movies");
+    ///
+    /// let movies_search = movies.clone().with_timeout(Some(Duration::from_secs(1)));
+    /// let movies_upload = movies.with_timeout(Some(Duration::from_secs(120)));
+    /// ```
+    pub fn with_timeout(self, timeout: Option<std::time::Duration>) -> Index {
+        let mut config = RequestConfig::clone(&self.config);
+        config.timeout = timeout;
+        Index { config: Rc::new(config), ..self }
+    }
+
+    /// Get a copy of this index whose requests are cancelled with `cancellation_token`, instead of the [one of its client](../client/struct.Client.html#method.with_cancellation_token).
+    ///
+    /// See [`CancellationToken`](../client/struct.CancellationToken.html) for an example.
+    pub fn with_cancellation_token(self, cancellation_token: Option<CancellationToken>) -> Index {
+        let mut config = RequestConfig::clone(&self.config);
+        config.cancellation_token = cancellation_token;
+        Index { config: Rc::new(config), ..self }
+    }
+
+    fn json_payload<T: Serialize>(&self, documents: &[T]) -> Result<Payload<'static>, Error> {
+        Ok(Payload {
+            content_type: PayloadFormat::Json.content_type(),
+            data: serde_json::to_vec(documents).map_err(Error::ParseError)?.into(),
+            compression: self.compression,
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn send_documents_stream(&self, method: Method<StreamPayload<'_>>, parameters: serde_json::Value) -> Result<TaskHandle, Error> {
+        Ok(request_stream::<TaskInfo>(
+            &format!("{}/indexes/{}/documents?{}", self.host, self.uid, query_string(&parameters)),
+            &self.config,
+            method,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    async fn send_documents_payload(&self, method: Method<Payload<'_>>, parameters: serde_json::Value) -> Result<TaskHandle, Error> {
+        Ok(request_payload::<TaskInfo>(
+            &format!("{}/indexes/{}/documents?{}", self.host, self.uid, query_string(&parameters)),
+            &self.config,
+            method,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Delete all documents in the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// #
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #    name: String,
+    /// #    description: String,
+    /// # }
+    /// #
+    /// # // that trait is used by the sdk when the primary key is needed
+    /// # impl Document for Movie {
+    /// #    type UIDType = String;
+    /// #    fn get_uid(&self) -> &Self::UIDType {
+    /// #        &self.name
+    /// #    }
+    /// # }
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// #
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// // add some documents
+    ///
+    /// let progress = movie_index.delete_all_documents().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # let movies = movie_index.get_documents::<Movie>(None, None, None).await.unwrap();
+    /// # assert_eq!(movies.len(), 0);
+    /// # });
+    /// ```
+    pub async fn delete_all_documents(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/documents", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Delete one document based on its unique id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// #
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #    name: String,
+    /// #    description: String,
+    /// # }
+    /// #
+    /// # // that trait is used by the sdk when the primary key is needed
     /// # impl Document for Movie {
     /// #    type UIDType = String;
     /// #    fn get_uid(&self) -> &Self::UIDType {
@@ -579,25 +1351,24 @@ impl Index {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movies = client.get_or_create("movies").await.unwrap();
     ///
-    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")}], Some("name")).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(1));
+    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")}], Some("name")).await.unwrap().wait().await.unwrap();
     /// // add a document with id = Interstellar
     ///
     /// let progress = movies.delete_document("Interstellar").await.unwrap();
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn delete_document<T: Display>(&self, uid: T) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
+    pub async fn delete_document<T: Display>(&self, uid: T) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
             &format!(
                 "{}/indexes/{}/documents/{}",
-                self.host, self.uid, uid
+                self.host, self.uid, percent_encode(&uid.to_string())
             ),
-            &self.api_key,
+            &self.config,
             Method::Delete,
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Delete a selection of documents based on array of document id's.
@@ -628,174 +1399,106 @@ impl Index {
     /// let mut movies = client.get_or_create("movies").await.unwrap();
     ///
     /// // add some documents
-    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")},Movie{name:String::from("Unknown"), description:String::from("Unknown")}], Some("name")).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(1));
+    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")},Movie{name:String::from("Unknown"), description:String::from("Unknown")}], Some("name")).await.unwrap().wait().await.unwrap();
     ///
     /// // delete some documents
     /// let progress = movies.delete_documents(&["Interstellar", "Unknown"]).await.unwrap();
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
     pub async fn delete_documents<T: Display + Serialize + std::fmt::Debug>(
         &self,
         uids: &[T],
-    ) -> Result<Progress, Error> {
-        Ok(request::<&[T], ProgressJson>(
+    ) -> Result<TaskHandle, Error> {
+        Ok(request::<&[T], TaskInfo>(
             &format!(
                 "{}/indexes/{}/documents/delete-batch",
                 self.host, self.uid
             ),
-            &self.api_key,
+            &self.config,
             Method::Post(uids),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Alias for the [update method](#method.update).
-    pub async fn set_primary_key(&self, primary_key: impl AsRef<str>) -> Result<(), Error> {
-        self.update(primary_key).await
-    }
-
-    /// Get the status of an update on the index.
-    ///
-    /// After executing an update, a `Progress` struct is returned,
-    /// you can use this struct to check on the status of the update.
-    ///
-    /// In some cases, you might not need the status of the update directly,
-    /// or would rather not wait for it to resolve.
-    ///
-    /// For these cases, you can get the `update_id` from the `Progress`
-    /// struct and use it to query the index later on.
-    ///
-    /// For example, if a clients updates an entry over an HTTP request,
-    /// you can respond with the `update_id` and have the client check
-    /// on the update status later on.
+    /// Delete all the documents matching a filter.
+    /// The filtered attributes must be [filterable](../settings/struct.Settings.html#structfield.filterable_attributes).
     ///
     /// # Example
     ///
     /// ```
-    /// # use serde::{Serialize, Deserialize};
-    /// # use std::thread::sleep;
-    /// # use std::time::Duration;
-    /// # use meilisearch_sdk::{client::*, document, indexes::*, progress::UpdateStatus};
-    /// #
-    /// # #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    /// # struct Document {
-    /// #    id: usize,
-    /// #    value: String,
-    /// #    kind: String,
-    /// # }
-    /// #
-    /// # impl document::Document for Document {
-    /// #    type UIDType = usize;
-    /// #
-    /// #    fn get_uid(&self) -> &Self::UIDType {
-    /// #        &self.id
-    /// #    }
-    /// # }
-    /// #
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::Filter};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let movies = client.get_or_create("movies_get_one_update").await.unwrap();
-    ///
-    /// let progress = movies.add_documents(&[
-    ///     Document { id: 0, kind: "title".into(), value: "The Social Network".to_string() }
-    /// ], None).await.unwrap();
-    ///
-    /// // Get update status directly on the progress object
-    /// let status = progress.get_status().await.unwrap();
-    /// let from_progress = match status {
-    ///    UpdateStatus::Enqueued{content} => content.update_id,
-    ///    UpdateStatus::Failed{content} => content.update_id,
-    ///    UpdateStatus::Processed{content} => content.update_id,
-    /// };
-    ///
-    /// let update_id = progress.get_update_id();
-    /// // Get update status from the index, using `update_id`
-    /// let status = movies.get_update(update_id).await.unwrap();
-    ///
-    /// let from_index = match status {
-    ///    UpdateStatus::Enqueued{content} => content.update_id,
-    ///    UpdateStatus::Failed{content} => content.update_id,
-    ///    UpdateStatus::Processed{content} => content.update_id,
-    /// };
-    ///
-    /// assert_eq!(from_progress, from_index);
-    /// assert_eq!(from_progress, update_id);
-    /// # client.delete_index("movies_get_one_update").await.unwrap();
+    /// let movies = client.get_or_create("movies_delete_documents_by_filter").await.unwrap();
+    /// # movies.set_filterable_attributes(["expired"]).await.unwrap().wait().await.unwrap();
+    ///
+    /// let task = movies.delete_documents_by_filter("expired = true").await.unwrap();
+    /// task.wait().await.unwrap();
+    /// # client.delete_index("movies_delete_documents_by_filter").await.unwrap();
     /// # });
     /// ```
-    pub async fn get_update(&self, update_id: u64) -> Result<UpdateStatus, Error> {
-        request::<(), UpdateStatus>(
-            &format!(
-                "{}/indexes/{}/updates/{}",
-                self.host, self.uid, update_id
-            ),
-            &self.api_key,
-            Method::Get,
-            200,
-        )
-        .await
+    pub async fn delete_documents_by_filter<'a>(&self, filter: impl Into<Filter<'a>>) -> Result<TaskHandle, Error> {
+        Ok(request::<serde_json::Value, TaskInfo>(
+            &format!("{}/indexes/{}/documents/delete", self.host, self.uid),
+            &self.config,
+            Method::Post(json!({ "filter": filter.into() })),
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Get the status of all updates in a given index.
+    /// Edit documents in place with a [Rhai](https://rhai.rs) function, without sending them again.
+    ///
+    /// The function modifies the document through the `doc` variable, and can read the `context` object as `context`.
+    /// Only the documents matching `filter` are edited, or all of them if it is `None`.
+    ///
+    /// This is an experimental feature of MeiliSearch, which must be enabled with the `editDocumentsByFunction` experimental feature.
     ///
     /// # Example
     ///
-    /// ```
-    /// # use serde::{Serialize, Deserialize};
-    /// # use std::thread::sleep;
-    /// # use std::time::Duration;
-    /// # use meilisearch_sdk::{client::*, document, indexes::*};
-    /// #
-    /// # #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    /// # struct Document {
-    /// #    id: usize,
-    /// #    value: String,
-    /// #    kind: String,
-    /// # }
-    /// #
-    /// # impl document::Document for Document {
-    /// #    type UIDType = usize;
-    /// #
-    /// #    fn get_uid(&self) -> &Self::UIDType {
-    /// #        &self.id
-    /// #    }
-    /// # }
-    /// #
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::Filter};
+    /// # use serde_json::json;
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let movies = client.get_or_create("movies_get_all_updates").await.unwrap();
-    ///
-    /// # movies.add_documents(&[
-    /// #     Document { id: 0, kind: "title".into(), value: "The Social Network".to_string() },
-    /// #     Document { id: 1, kind: "title".into(), value: "Harry Potter and the Sorcerer's Stone".to_string() },
-    /// # ], None).await.unwrap();
-    /// # sleep(Duration::from_secs(1));
-    ///
-    /// # movies.add_documents(&[
-    /// #    Document { id: 0, kind: "title".into(), value: "Harry Potter and the Chamber of Secrets".to_string() },
-    /// #    Document { id: 1, kind: "title".into(), value: "Harry Potter and the Prisoner of Azkaban".to_string() },
-    /// # ], None).await.unwrap();
-    /// # sleep(Duration::from_secs(1));
-    ///
-    /// let status = movies.get_all_updates().await.unwrap();
-    /// assert!(status.len() >= 2);
-    /// # client.delete_index("movies_get_all_updates").await.unwrap();
+    /// let products = client.get_or_create("products").await.unwrap();
+    ///
+    /// let task = products.edit_documents_by_function(
+    ///     "doc.price = doc.price * (100 - context.discount) / 100",
+    ///     Some(json!({ "discount": 20 })),
+    ///     Some(Filter::Expression("category = shoes")),
+    /// ).await.unwrap();
+    /// task.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn get_all_updates(&self) -> Result<Vec<UpdateStatus>, Error> {
-        request::<(), Vec<UpdateStatus>>(
-            &format!(
-                "{}/indexes/{}/updates",
-                self.host, self.uid
-            ),
-            &self.api_key,
-            Method::Get,
-            200,
-        )
-        .await
+    pub async fn edit_documents_by_function<'a>(
+        &self,
+        function: &str,
+        context: Option<serde_json::Value>,
+        filter: Option<Filter<'a>>,
+    ) -> Result<TaskHandle, Error> {
+        let mut body = serde_json::Map::new();
+        body.insert(String::from("function"), json!(function));
+        if let Some(context) = context {
+            body.insert(String::from("context"), context);
+        }
+        if let Some(filter) = filter {
+            body.insert(String::from("filter"), json!(filter));
+        }
+        Ok(request::<serde_json::Map<String, serde_json::Value>, TaskInfo>(
+            &format!("{}/indexes/{}/documents/edit", self.host, self.uid),
+            &self.config,
+            Method::Post(body),
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Alias for the [update method](#method.update).
+    pub async fn set_primary_key(&self, primary_key: impl AsRef<str>) -> Result<TaskHandle, Error> {
+        self.update(primary_key).await
     }
 
     /// Get stats of an index.
@@ -815,55 +1518,666 @@ impl Index {
     pub async fn get_stats(&self) -> Result<IndexStats, Error> {
         request::<serde_json::Value, IndexStats>(
             &format!("{}/indexes/{}/stats", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await
     }
 }
 
-#[derive(Deserialize)]
+/// The encoding of documents sent as is, by [`Index::add_documents_from_reader`] or [`Index::add_documents_from_stream`] for example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadFormat {
+    /// A JSON array of documents
+    Json,
+    /// One JSON document per line, see [ndjson.org](http://ndjson.org/)
+    Ndjson,
+    /// CSV with a header line, see [`Index::add_documents_csv`]
+    Csv {
+        /// The field separator, which must be an ASCII character (default: `,`)
+        delimiter: Option<char>,
+    },
+}
+
+impl PayloadFormat {
+    fn content_type(&self) -> &'static str {
+        match self {
+            PayloadFormat::Json => "application/json",
+            PayloadFormat::Ndjson => "application/x-ndjson",
+            PayloadFormat::Csv { .. } => "text/csv",
+        }
+    }
+
+    fn parameters(&self, primary_key: Option<&str>) -> serde_json::Value {
+        match self {
+            PayloadFormat::Csv { delimiter } => json!({ "primaryKey": primary_key, "csvDelimiter": delimiter }),
+            _ => json!({ "primaryKey": primary_key }),
+        }
+    }
+}
+
+/// Statistics of an index, returned by [`Index::get_stats`].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct IndexStats {
     pub number_of_documents: usize,
+    /// Whether a task is being processed on the index
     pub is_indexing: bool,
-    pub fields_distribution: HashMap<String, usize>,
+    /// The number of documents having each field
+    #[serde(alias = "fieldsDistribution")]
+    pub field_distribution: HashMap<String, usize>,
+}
+
+/// The declaration of an index: its UID, its primary key and its settings.
+/// Use [`Client::ensure_index`](../client/struct.Client.html#method.ensure_index) to make the server match the declaration.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{indexes::IndexSpec, settings::Settings};
+/// let spec = IndexSpec::new("movies")
+///     .with_primary_key("id")
+///     .with_settings(Settings::new().with_filterable_attributes(["genres"]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexSpec {
+    /// The UID of the index
+    pub uid: String,
+    /// The primary key of the index. If `None`, MeiliSearch infers it from the first documents.
+    pub primary_key: Option<String>,
+    /// The settings of the index. Settings that are `None` are left unchanged.
+    pub settings: Settings,
+    /// How long to wait for each task of [`Client::ensure_index`](../client/struct.Client.html#method.ensure_index) to be processed (default: 5 seconds)
+    pub timeout: Option<std::time::Duration>,
+}
+
+#[allow(missing_docs)]
+impl IndexSpec {
+    /// Declare an index with undefined settings
+    pub fn new(uid: impl Into<String>) -> IndexSpec {
+        IndexSpec {
+            uid: uid.into(),
+            primary_key: None,
+            settings: Settings::new(),
+            timeout: None,
+        }
+    }
+    pub fn with_primary_key(self, primary_key: impl Into<String>) -> IndexSpec {
+        IndexSpec {
+            primary_key: Some(primary_key.into()),
+            ..self
+        }
+    }
+    pub fn with_settings(self, settings: Settings) -> IndexSpec {
+        IndexSpec {
+            settings,
+            ..self
+        }
+    }
+    pub fn with_timeout(self, timeout: std::time::Duration) -> IndexSpec {
+        IndexSpec {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::*, progress::UpdateStatus};
+    use crate::{client::*, document::Document, errors::Error, indexes::PayloadFormat, tasks::{TaskStatus, TaskType}};
     use futures_await_test::async_test;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Element {
+        id: usize,
+        value: String,
+    }
+
+    impl Document for Element {
+        type UIDType = usize;
+
+        fn get_uid(&self) -> &Self::UIDType {
+            &self.id
+        }
+    }
+
+    fn elements(count: usize) -> Vec<Element> {
+        (0..count).map(|id| Element { id, value: format!("value {}", id) }).collect()
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct ValidatedElement {
+        id: usize,
+        value: String,
+    }
+
+    impl Document for ValidatedElement {
+        type UIDType = usize;
+
+        fn get_uid(&self) -> &Self::UIDType {
+            &self.id
+        }
+
+        fn validate(&self) -> Result<(), String> {
+            if self.value.is_empty() {
+                Err(String::from("empty value"))
+            } else {
+                Ok(())
+            }
+        }
+    }
 
     #[async_test]
-    async fn test_get_all_updates_no_docs() {
+    async fn test_add_invalid_documents() {
         let client = Client::new("http://localhost:7700", "masterKey");
-        let uid = "test_get_all_updates_no_docs";
+        let uid = "test_add_invalid_documents";
+        let index = client.get_or_create(uid).await.unwrap();
 
+        let documents: Vec<ValidatedElement> = (0..5)
+            .map(|id| ValidatedElement { id, value: if id % 2 == 1 { String::new() } else { format!("value {}", id) } })
+            .collect();
+        for result in [
+            index.add_documents(&documents, Some("id")).await.map(|_| ()),
+            index.add_documents_in_batches(&documents, Some(1), Some("id")).await.map(|_| ()).map_err(Error::from),
+            index.add_or_update(&documents, Some("id")).await.map(|_| ()),
+        ] {
+            match result {
+                Err(Error::InvalidDocuments(invalid_documents)) => {
+                    let uids: Vec<&str> = invalid_documents.iter().map(|document| document.uid.as_str()).collect();
+                    assert_eq!(uids, ["1", "3"]);
+                    assert_eq!(invalid_documents[1].position, 3);
+                    assert_eq!(invalid_documents[1].reason, "empty value");
+                }
+                other => panic!("expected invalid documents, got {:?}", other),
+            }
+        }
+        assert_eq!(index.get_stats().await.unwrap().number_of_documents, 0);
+        client.delete_index(uid).await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_get_stats() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_get_stats";
         let index = client.get_or_create(uid).await.unwrap();
-        let status = index.get_all_updates().await.unwrap();
+        index.add_documents(&elements(3), Some("id")).await.unwrap().wait().await.unwrap();
+
+        let stats = index.get_stats().await.unwrap();
+        assert_eq!(stats.number_of_documents, 3);
+        assert_eq!(stats.field_distribution["id"], 3);
+        assert_eq!(stats.field_distribution["value"], 3);
+
+        let stats = client.get_stats().await.unwrap();
+        assert_eq!(stats.indexes[uid].number_of_documents, 3);
         client.delete_index(uid).await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_create_update_delete_index() {
+        use crate::{indexes::JsonIndex, request::*};
 
-        assert_eq!(status.len(), 0);
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_create_update_delete_index";
+        client.delete_index_if_exists(uid).await.unwrap();
+
+        let task = client.create_index(uid, None).await.unwrap();
+        assert_eq!(task.index_uid.as_deref(), Some(uid));
+        assert_eq!(task.task_type, TaskType::IndexCreation);
+        assert_eq!(task.wait_for_completion(&client, None, None).await.unwrap().status, TaskStatus::Succeeded);
+
+        // Creating the index again makes the task fail
+        let task = client.create_index(uid, None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Failed);
+        assert_eq!(task.error.unwrap().code, "index_already_exists");
+
+        let index = client.get_index(uid).await.unwrap();
+        let task = index.update("reference").await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        let json_index = request::<(), JsonIndex>(
+            &format!("{}/indexes/{}", index.host, index.uid),
+            &index.config,
+            Method::Get,
+            200,
+        ).await.unwrap();
+        assert_eq!(json_index.primaryKey.as_deref(), Some("reference"));
+
+        let task = index.clone().delete().await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        assert!(client.get_index(uid).await.is_err());
+        assert!(!index.delete_if_exists().await.unwrap());
     }
 
     #[async_test]
-    async fn test_get_one_update() {
+    async fn test_add_documents_in_batches() {
         let client = Client::new("http://localhost:7700", "masterKey");
-        let uid = "test_get_one_update";
+        let uid = "test_add_documents_in_batches";
 
         let index = client.get_or_create(uid).await.unwrap();
-        let progress = index.delete_all_documents().await.unwrap();
+        let handles = index.add_documents_in_batches(&elements(5), Some(2), Some("id")).await.unwrap();
+        assert_eq!(handles.len(), 3);
+        for handle in &handles {
+            assert_eq!(handle.wait().await.unwrap().status, TaskStatus::Succeeded);
+        }
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(documents.len(), 5);
+    }
+
+    #[async_test]
+    async fn test_update_documents_in_batches() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_update_documents_in_batches";
 
-        let update_id = progress.get_update_id();
-        let status = index.get_update(update_id).await.unwrap();
+        let index = client.get_or_create(uid).await.unwrap();
+        index.add_documents(&elements(3), Some("id")).await.unwrap().wait().await.unwrap();
 
+        let updates: Vec<serde_json::Value> = (0..3).map(|id| serde_json::json!({ "id": id, "value": "updated" })).collect();
+        let handles = index.update_documents_in_batches(&updates, Some(2), None).await.unwrap();
+        assert_eq!(handles.len(), 2);
+        for handle in &handles {
+            assert_eq!(handle.wait().await.unwrap().status, TaskStatus::Succeeded);
+        }
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
         client.delete_index(uid).await.unwrap();
 
-        match status {
-            UpdateStatus::Enqueued{content} => assert_eq!(content.update_id, update_id),
-            UpdateStatus::Failed{content} => assert_eq!(content.update_id, update_id),
-            UpdateStatus::Processed{content} => assert_eq!(content.update_id, update_id),
+        assert_eq!(documents.len(), 3);
+        assert!(documents.iter().all(|document| document.value == "updated"));
+    }
+
+    #[async_test]
+    async fn test_add_documents_in_batches_error() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Sends the second batch with an invalid key
+        struct FailSecondBatch(AtomicUsize);
+
+        impl Middleware for FailSecondBatch {
+            fn on_request(&self, request: &mut MiddlewareRequest) {
+                if request.method == "POST" && request.url.contains("/documents") && self.0.fetch_add(1, Ordering::SeqCst) == 1 {
+                    request.headers.push(("Authorization".to_string(), "Bearer invalidKey".to_string()));
+                }
+            }
         }
+
+        let client = Client::new("http://localhost:7700", "masterKey").with_middleware(FailSecondBatch(AtomicUsize::new(0)));
+        let uid = "test_add_documents_in_batches_error";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        let error = index.add_documents_in_batches(&elements(5), Some(2), Some("id")).await.unwrap_err();
+        // The first batch was enqueued, and the third one was not sent
+        assert_eq!(error.handles.len(), 1);
+        assert!(matches!(error.error, Error::Meilisearch(_)));
+        assert_eq!(error.handles[0].wait().await.unwrap().status, TaskStatus::Succeeded);
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(documents.len(), 2);
+    }
+
+    #[async_test]
+    async fn test_add_documents_ndjson() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_add_documents_ndjson";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        let payload = "{ \"id\": 1, \"value\": \"one\" }\n{ \"id\": 2, \"value\": \"two\" }\n";
+        let task = index.add_documents_ndjson(payload.as_bytes(), Some("id")).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let payload = "{ \"id\": 2, \"value\": \"updated\" }\n";
+        let task = index.update_documents_ndjson(payload.as_bytes(), None).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(documents, vec![
+            Element { id: 1, value: String::from("one") },
+            Element { id: 2, value: String::from("updated") },
+        ]);
+    }
+
+    #[async_test]
+    async fn test_add_documents_csv() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_add_documents_csv";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        let payload = "id:number;value\n1;one\n2;two\n";
+        let task = index.add_documents_csv(payload.as_bytes(), Some(';'), Some("id")).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let payload = "id:number,value\n2,updated\n";
+        let task = index.update_documents_csv(payload.as_bytes(), None, None).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(documents, vec![
+            Element { id: 1, value: String::from("one") },
+            Element { id: 2, value: String::from("updated") },
+        ]);
+    }
+
+    #[async_test]
+    async fn test_add_documents_from_stream() {
+        use futures::stream;
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_add_documents_from_stream";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        let chunks: Vec<std::io::Result<Vec<u8>>> = vec![
+            Ok(b"[{ \"id\": 1, \"value\": \"one\" }, { \"id\"".to_vec()),
+            Ok(b": 2, \"value\": \"two\" }]".to_vec()),
+        ];
+        let task = index.add_documents_from_stream(stream::iter(chunks), PayloadFormat::Json, Some("id")).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let reader = futures::io::Cursor::new(b"id:number|value\n2|updated\n".to_vec());
+        let format = PayloadFormat::Csv { delimiter: Some('|') };
+        let task = index.update_documents_from_reader(reader, format, None).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(documents, vec![
+            Element { id: 1, value: String::from("one") },
+            Element { id: 2, value: String::from("updated") },
+        ]);
+    }
+
+    #[cfg(feature = "compression")]
+    #[async_test]
+    async fn test_compression() {
+        use crate::client::Compression;
+
+        let client = Client::new("http://localhost:7700", "masterKey").with_compression(Some(Compression::Gzip));
+        let uid = "test_compression";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        let task = index.add_documents(&elements(2), Some("id")).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        for compression in [Compression::Deflate, Compression::Brotli].iter() {
+            let payload = "id:number,value\n1,updated\n";
+            let task = index
+                .clone()
+                .with_compression(Some(*compression))
+                .update_documents_csv(payload.as_bytes(), None, None)
+                .await
+                .unwrap()
+                .wait()
+                .await
+                .unwrap();
+            assert_eq!(task.status, TaskStatus::Succeeded);
+        }
+
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(documents, vec![
+            Element { id: 0, value: String::from("value 0") },
+            Element { id: 1, value: String::from("updated") },
+        ]);
+    }
+
+    #[async_test]
+    async fn test_max_payload_size() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_max_payload_size";
+
+        // Each element is serialized in 26 bytes, plus the brackets and commas of the array
+        let index = client.get_or_create(uid).await.unwrap().with_max_payload_size(Some(60));
+        let handles = index.add_documents_in_batches(&elements(5), None, Some("id")).await.unwrap();
+        assert_eq!(handles.len(), 3);
+        for handle in &handles {
+            assert_eq!(handle.wait().await.unwrap().status, TaskStatus::Succeeded);
+        }
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(documents, elements(5));
+    }
+
+    #[async_test]
+    async fn test_upload_concurrency() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_upload_concurrency";
+
+        let index = client.get_or_create(uid).await.unwrap().with_upload_concurrency(3);
+        let handles = index.add_documents_in_batches(&elements(10), Some(2), Some("id")).await.unwrap();
+        assert_eq!(handles.len(), 5);
+        for handle in &handles {
+            assert_eq!(handle.wait().await.unwrap().status, TaskStatus::Succeeded);
+        }
+        let mut documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        documents.sort_by_key(|document| document.id);
+        assert_eq!(documents, elements(10));
+    }
+
+    #[async_test]
+    async fn test_fetch_documents() {
+        use crate::document::DocumentsQuery;
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_fetch_documents";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        index.set_filterable_attributes(["id"]).await.unwrap().wait().await.unwrap();
+        index.add_documents(&elements(10), Some("id")).await.unwrap().wait().await.unwrap();
+
+        let page = DocumentsQuery::new(&index)
+            .with_filter("id >= 5")
+            .with_fields(&["id"])
+            .with_offset(1)
+            .with_limit(2)
+            .execute::<serde_json::Value>()
+            .await
+            .unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(page.total, 5);
+        assert_eq!(page.offset, 1);
+        assert_eq!(page.limit, 2);
+        assert_eq!(page.results.len(), 2);
+        assert!(page.results.iter().all(|document| document.get("value").is_none()));
+    }
+
+    #[async_test]
+    async fn test_documents_stream() {
+        use futures::TryStreamExt;
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_documents_stream";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        index.add_documents_in_batches(&elements(2500), None, Some("id")).await.unwrap().last().unwrap().wait().await.unwrap();
+
+        let mut documents: Vec<Element> = index.documents_stream::<Element>().try_collect().await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        documents.sort_by_key(|document| document.id);
+        assert_eq!(documents, elements(2500));
+    }
+
+    #[async_test]
+    async fn test_delete_documents_by_filter() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_delete_documents_by_filter";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        index.set_filterable_attributes(["id"]).await.unwrap().wait().await.unwrap();
+        index.add_documents(&elements(5), Some("id")).await.unwrap().wait().await.unwrap();
+
+        let task = index.delete_documents_by_filter("id < 3").await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        let mut documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        documents.sort_by_key(|document| document.id);
+        assert_eq!(documents, elements(5).split_off(3));
+    }
+
+    #[async_test]
+    async fn test_delete_documents() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_delete_documents";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        index.add_documents(&elements(5), Some("id")).await.unwrap().wait().await.unwrap();
+
+        let task = index.delete_documents(&[0, 2, 4]).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        let mut documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        documents.sort_by_key(|document| document.id);
+        assert_eq!(documents.iter().map(|document| document.id).collect::<Vec<_>>(), vec![1, 3]);
+
+        let task = index.delete_all_documents().await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        let documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert!(documents.is_empty());
+    }
+
+    #[async_test]
+    async fn test_add_documents_with_primary_key() {
+        use crate::{indexes::JsonIndex, request::*};
+
+        #[derive(serde::Serialize, Debug)]
+        struct Product {
+            sku: String,
+            reference: String,
+        }
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_add_documents_with_primary_key";
+
+        // No field name contains `id`, so the primary key can't be inferred
+        let index = client.get_or_create(uid).await.unwrap();
+        let products = [Product { sku: String::from("a-1"), reference: String::from("r-1") }];
+        let task = index.update_documents(&products, Some("reference")).await.unwrap().wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let json_index = request::<(), JsonIndex>(
+            &format!("{}/indexes/{}", index.host, index.uid),
+            &index.config,
+            Method::Get,
+            200,
+        ).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(json_index.primaryKey.as_deref(), Some("reference"));
+    }
+
+    #[async_test]
+    async fn test_get_document_with_fields() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_get_document_with_fields";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        index.add_documents(&elements(2), Some("id")).await.unwrap().wait().await.unwrap();
+
+        let document = index.get_document_with_fields::<serde_json::Value>(1, &["value"]).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        assert_eq!(document, serde_json::json!({ "value": "value 1" }));
+    }
+
+    #[async_test]
+    async fn test_document_urls_are_encoded() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Serialize, Deserialize, Debug)]
+        struct Page {
+            id: String,
+        }
+
+        impl Document for Page {
+            type UIDType = String;
+
+            fn get_uid(&self) -> &Self::UIDType {
+                &self.id
+            }
+        }
+
+        struct RecordUrls(Arc<Mutex<Vec<String>>>);
+
+        impl Middleware for RecordUrls {
+            fn on_request(&self, request: &mut MiddlewareRequest) {
+                self.0.lock().unwrap().push(request.url.to_string());
+            }
+        }
+
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::new("http://localhost:7700", "masterKey").with_middleware(RecordUrls(urls.clone()));
+        let uid = "test_document_urls_are_encoded";
+
+        let index = client.get_or_create(uid).await.unwrap();
+        urls.lock().unwrap().clear();
+        let _ = index.get_document::<Page>(String::from("a/b?c#d")).await;
+        let _ = index.get_document_with_fields::<Page>("a/b?c#d", &["id"]).await;
+        index.delete_document("a/b?c#d").await.unwrap().wait().await.unwrap();
+        client.delete_index(uid).await.unwrap();
+
+        let urls = urls.lock().unwrap();
+        assert!(urls.len() >= 3);
+        for url in &urls[..3] {
+            assert!(url.contains("/documents/a%2Fb%3Fc%23d"), "{}", url);
+        }
+    }
+
+    #[async_test]
+    async fn test_edit_documents_by_function() {
+        use crate::request::*;
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let uid = "test_edit_documents_by_function";
+
+        let features_url = format!("{}/experimental-features", client.host);
+        let features = request::<(), serde_json::Value>(&features_url, &client.config, Method::Get, 200).await.unwrap();
+        let was_enabled = features["editDocumentsByFunction"].as_bool().unwrap_or(false);
+        request::<serde_json::Value, serde_json::Value>(
+            &features_url,
+            &client.config,
+            Method::Patch(serde_json::json!({ "editDocumentsByFunction": true })),
+            200,
+        ).await.unwrap();
+
+        let index = client.get_or_create(uid).await.unwrap();
+        index.set_filterable_attributes(["id"]).await.unwrap().wait().await.unwrap();
+        index.add_documents(&elements(3), Some("id")).await.unwrap().wait().await.unwrap();
+
+        let task = index
+            .edit_documents_by_function(
+                "doc.value = context.prefix + doc.value",
+                Some(serde_json::json!({ "prefix": "edited " })),
+                Some("id > 0".into()),
+            )
+            .await
+            .unwrap()
+            .wait()
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        let mut documents = index.get_documents::<Element>(None, None, None).await.unwrap();
+        client.delete_index(uid).await.unwrap();
+        request::<serde_json::Value, serde_json::Value>(
+            &features_url,
+            &client.config,
+            Method::Patch(serde_json::json!({ "editDocumentsByFunction": was_enabled })),
+            200,
+        ).await.unwrap();
+
+        documents.sort_by_key(|document| document.id);
+        assert_eq!(documents.iter().map(|document| document.value.as_str()).collect::<Vec<_>>(), vec!["value 0", "edited value 1", "edited value 2"]);
     }
 }
@@ -0,0 +1,410 @@
+//! The `key` module allows managing the API keys of a MeiliSearch instance.
+//!
+//! Each key is allowed to perform some [actions](Action) on some indexes, until an optional expiration date.
+//! Managing keys requires the master key, or a key allowed to perform the `keys.*` actions.
+//!
+//! # Example
+//!
+//! ```
+//! # use meilisearch_sdk::{client::*, key::*};
+//! # futures::executor::block_on(async move {
+//! let client = Client::new("http://localhost:7700", "masterKey");
+//!
+//! // Create a key only allowed to search in the movies index
+//! let key = client
+//!     .create_key(&KeyBuilder::new([Action::Search], ["movies"]).with_name("Movies search"))
+//!     .await
+//!     .unwrap();
+//!
+//! // Use it in a search-only client
+//! let search_client = Client::new("http://localhost:7700", &key.key);
+//! # client.delete_key(&key.uid).await.unwrap();
+//! # });
+//! ```
+
+use crate::{client::Client, errors::Error, request::*};
+use serde::{Deserialize, Serialize};
+
+/// An action that an API [key](Key) can be allowed to perform.
+///
+/// Actions ending with `All` allow all the actions of their group, such as [`Action::DocumentsAll`] for `documents.*`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// All the actions (`*`)
+    #[serde(rename = "*")]
+    All,
+    #[serde(rename = "search")]
+    Search,
+    #[serde(rename = "documents.*")]
+    DocumentsAll,
+    #[serde(rename = "documents.add")]
+    DocumentsAdd,
+    #[serde(rename = "documents.get")]
+    DocumentsGet,
+    #[serde(rename = "documents.delete")]
+    DocumentsDelete,
+    #[serde(rename = "indexes.*")]
+    IndexesAll,
+    #[serde(rename = "indexes.create")]
+    IndexesCreate,
+    #[serde(rename = "indexes.get")]
+    IndexesGet,
+    #[serde(rename = "indexes.update")]
+    IndexesUpdate,
+    #[serde(rename = "indexes.delete")]
+    IndexesDelete,
+    #[serde(rename = "indexes.swap")]
+    IndexesSwap,
+    #[serde(rename = "tasks.*")]
+    TasksAll,
+    #[serde(rename = "tasks.get")]
+    TasksGet,
+    #[serde(rename = "tasks.cancel")]
+    TasksCancel,
+    #[serde(rename = "tasks.delete")]
+    TasksDelete,
+    #[serde(rename = "settings.*")]
+    SettingsAll,
+    #[serde(rename = "settings.get")]
+    SettingsGet,
+    #[serde(rename = "settings.update")]
+    SettingsUpdate,
+    #[serde(rename = "stats.*")]
+    StatsAll,
+    #[serde(rename = "stats.get")]
+    StatsGet,
+    #[serde(rename = "metrics.*")]
+    MetricsAll,
+    #[serde(rename = "metrics.get")]
+    MetricsGet,
+    #[serde(rename = "dumps.*")]
+    DumpsAll,
+    #[serde(rename = "dumps.create")]
+    DumpsCreate,
+    #[serde(rename = "snapshots.*")]
+    SnapshotsAll,
+    #[serde(rename = "snapshots.create")]
+    SnapshotsCreate,
+    #[serde(rename = "version")]
+    Version,
+    #[serde(rename = "keys.create")]
+    KeysCreate,
+    #[serde(rename = "keys.get")]
+    KeysGet,
+    #[serde(rename = "keys.update")]
+    KeysUpdate,
+    #[serde(rename = "keys.delete")]
+    KeysDelete,
+    /// An action unknown to this version of the SDK. It can't be sent to MeiliSearch.
+    #[serde(other)]
+    Unknown,
+}
+
+/// An API key, as returned by MeiliSearch.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Key {
+    /// The unique identifier of the key, used to manage it
+    pub uid: String,
+    /// The key itself, to be used in the `Authorization` header
+    pub key: String,
+    /// A human-readable name for the key
+    pub name: Option<String>,
+    /// A description of what the key is used for
+    pub description: Option<String>,
+    /// The actions the key is allowed to perform
+    pub actions: Vec<Action>,
+    /// The uids of the indexes the key can access. They can end with a `*` wildcard, and `*` alone matches all indexes.
+    pub indexes: Vec<String>,
+    /// The [RFC 3339](https://www.ietf.org/rfc/rfc3339.txt) date after which the key is expired, if any
+    pub expires_at: Option<String>,
+    /// The [RFC 3339](https://www.ietf.org/rfc/rfc3339.txt) date at which the key was created
+    pub created_at: String,
+    /// The [RFC 3339](https://www.ietf.org/rfc/rfc3339.txt) date at which the key was last updated
+    pub updated_at: String,
+}
+
+/// A page of [keys](Key), returned by [`Client::get_keys`].
+#[derive(Deserialize, Debug, Clone)]
+pub struct KeysResults {
+    pub results: Vec<Key>,
+    pub offset: usize,
+    pub limit: usize,
+    pub total: usize,
+}
+
+/// The description of a [key](Key) to create with [`Client::create_key`].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::key::*;
+/// let key = KeyBuilder::new([Action::DocumentsAdd, Action::DocumentsDelete], ["products_*"])
+///     .with_description("Used by the import job")
+///     .with_expires_at("2042-04-02T00:42:42Z");
+/// ```
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyBuilder {
+    /// A human-readable name for the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A description of what the key is used for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The uid of the key, which must be a UUID v4. It is generated by MeiliSearch if not provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// The actions the key will be allowed to perform
+    pub actions: Vec<Action>,
+    /// The uids of the indexes the key will be able to access. They can end with a `*` wildcard, and `*` alone matches all indexes.
+    pub indexes: Vec<String>,
+    /// The [RFC 3339](https://www.ietf.org/rfc/rfc3339.txt) date after which the key is expired. The key never expires if it is `None`.
+    pub expires_at: Option<String>,
+}
+
+#[allow(missing_docs)]
+impl KeyBuilder {
+    /// Describe a key allowed to perform some `actions` on some `indexes`, which never expires.
+    pub fn new(actions: impl IntoIterator<Item = Action>, indexes: impl IntoIterator<Item = impl Into<String>>) -> KeyBuilder {
+        KeyBuilder {
+            name: None,
+            description: None,
+            uid: None,
+            actions: actions.into_iter().collect(),
+            indexes: indexes.into_iter().map(Into::into).collect(),
+            expires_at: None,
+        }
+    }
+    pub fn with_name(self, name: impl Into<String>) -> KeyBuilder {
+        KeyBuilder {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+    pub fn with_description(self, description: impl Into<String>) -> KeyBuilder {
+        KeyBuilder {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+    pub fn with_uid(self, uid: impl Into<String>) -> KeyBuilder {
+        KeyBuilder {
+            uid: Some(uid.into()),
+            ..self
+        }
+    }
+    pub fn with_expires_at(self, expires_at: impl Into<String>) -> KeyBuilder {
+        KeyBuilder {
+            expires_at: Some(expires_at.into()),
+            ..self
+        }
+    }
+}
+
+/// The changes to apply to a [key](Key) with [`Client::update_key`].
+///
+/// Only the name and the description of a key can be updated.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyUpdater {
+    /// The new name of the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The new description of the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[allow(missing_docs)]
+impl KeyUpdater {
+    pub fn new() -> KeyUpdater {
+        KeyUpdater::default()
+    }
+    pub fn with_name(self, name: impl Into<String>) -> KeyUpdater {
+        KeyUpdater {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+    pub fn with_description(self, description: impl Into<String>) -> KeyUpdater {
+        KeyUpdater {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+}
+
+/// Key related methods.\
+/// See the [key](crate::key) module.
+impl Client {
+    /// Get the first page of the API keys of the instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::Error};
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let keys = client.get_keys().await.unwrap();
+    /// assert!(keys.total >= keys.results.len());
+    /// # });
+    /// ```
+    pub async fn get_keys(&self) -> Result<KeysResults, Error> {
+        request::<(), KeysResults>(
+            &format!("{}/keys", self.host),
+            &self.config,
+            Method::Get,
+            200,
+        ).await
+    }
+
+    /// Get an API key from its uid or from the key itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, key::*};
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # let created = client.create_key(&KeyBuilder::new([Action::Search], ["*"])).await.unwrap();
+    /// # let uid = created.uid.clone();
+    /// let key = client.get_key(&uid).await.unwrap();
+    /// assert_eq!(key.actions, [Action::Search]);
+    /// # client.delete_key(&uid).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_key(&self, key: impl AsRef<str>) -> Result<Key, Error> {
+        request::<(), Key>(
+            &format!("{}/keys/{}", self.host, key.as_ref()),
+            &self.config,
+            Method::Get,
+            200,
+        ).await
+    }
+
+    /// Create an API key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, key::*};
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let key = client
+    ///     .create_key(&KeyBuilder::new([Action::DocumentsAll, Action::TasksGet], ["movies"]).with_description("Movies import"))
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(key.description.as_deref(), Some("Movies import"));
+    /// # client.delete_key(&key.uid).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn create_key(&self, key: &KeyBuilder) -> Result<Key, Error> {
+        request::<&KeyBuilder, Key>(
+            &format!("{}/keys", self.host),
+            &self.config,
+            Method::Post(key),
+            201,
+        ).await
+    }
+
+    /// Update the name or the description of an API key, from its uid or from the key itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, key::*};
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # let key = client.create_key(&KeyBuilder::new([Action::Search], ["*"])).await.unwrap();
+    /// let key = client.update_key(&key.uid, &KeyUpdater::new().with_name("Search")).await.unwrap();
+    /// assert_eq!(key.name.as_deref(), Some("Search"));
+    /// # client.delete_key(&key.uid).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn update_key(&self, key: impl AsRef<str>, changes: &KeyUpdater) -> Result<Key, Error> {
+        request::<&KeyUpdater, Key>(
+            &format!("{}/keys/{}", self.host, key.as_ref()),
+            &self.config,
+            Method::Patch(changes),
+            200,
+        ).await
+    }
+
+    /// Delete an API key, from its uid or from the key itself. It can't be used anymore.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, key::*};
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let key = client.create_key(&KeyBuilder::new([Action::Search], ["*"])).await.unwrap();
+    /// client.delete_key(&key.uid).await.unwrap();
+    /// assert!(client.get_key(&key.uid).await.is_err());
+    /// # });
+    /// ```
+    pub async fn delete_key(&self, key: impl AsRef<str>) -> Result<(), Error> {
+        request::<(), ()>(
+            &format!("{}/keys/{}", self.host, key.as_ref()),
+            &self.config,
+            Method::Delete,
+            204,
+        ).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_await_test::async_test;
+
+    #[test]
+    fn test_key_builder_serialization() {
+        let key = KeyBuilder::new([Action::Search, Action::DocumentsAll], ["movies", "books_*"]).with_name("name");
+        assert_eq!(
+            serde_json::to_value(&key).unwrap(),
+            serde_json::json!({
+                "name": "name",
+                "actions": ["search", "documents.*"],
+                "indexes": ["movies", "books_*"],
+                "expiresAt": null,
+            })
+        );
+        assert_eq!(serde_json::from_str::<Action>(r#""tasks.cancel""#).unwrap(), Action::TasksCancel);
+        assert_eq!(serde_json::from_str::<Action>(r#""experimental.feature""#).unwrap(), Action::Unknown);
+    }
+
+    #[async_test]
+    async fn test_keys() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+
+        let key = client
+            .create_key(
+                &KeyBuilder::new([Action::Search], ["test_keys"])
+                    .with_description("test_keys")
+                    .with_expires_at("2050-01-01T00:00:00Z"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(key.actions, [Action::Search]);
+        assert_eq!(key.indexes, ["test_keys"]);
+        assert!(key.expires_at.as_deref().unwrap().starts_with("2050-01-01"));
+
+        let keys = client.get_keys().await.unwrap();
+        assert!(keys.total >= 1);
+        assert_eq!(client.get_key(&key.key).await.unwrap().uid, key.uid);
+
+        let updated = client.update_key(&key.uid, &KeyUpdater::new().with_name("test_keys")).await.unwrap();
+        assert_eq!(updated.name.as_deref(), Some("test_keys"));
+        assert_eq!(updated.description.as_deref(), Some("test_keys"));
+
+        client.delete_key(&key.uid).await.unwrap();
+        assert!(client.get_key(&key.uid).await.is_err());
+    }
+}
@@ -15,10 +15,21 @@
 //! ```
 //!
 //! This crate is `async` but you can choose to use an async runtime like [tokio](https://crates.io/crates/tokio) or just [block on futures](https://docs.rs/futures/latest/futures/executor/fn.block_on.html).  
+//! It doesn't depend on any runtime: requests are driven by the background thread of isahc (or by the browser), so it works the same with [tokio](https://crates.io/crates/tokio), [async-std](https://crates.io/crates/async-std), [smol](https://crates.io/crates/smol) or any other executor.  
 //! You can enable the `sync` feature to make most structs `Sync`. It may be a bit slower.
+//! Enable the `tracing` feature to run each request in a `meilisearch.request` [tracing](https://crates.io/crates/tracing) span, recording its method, endpoint, index uid, status and duration.
 //!
 //! Using this crate is possible without [serde](https://crates.io/crates/serde), but a lot of features require serde.
 //!
+//! Outside of browsers, requests are sent with [isahc](https://crates.io/crates/isahc), which relies on libcurl.
+//! With the default `isahc-static-curl` feature, libcurl is built and linked statically, and uses the TLS library of the platform (OpenSSL on Linux).
+//! Enable the `isahc-static-ssl` feature to link OpenSSL statically too, for static musl builds and minimal containers,
+//! or disable the default features to link the libcurl of the system instead:
+//!
+//! ```toml
+//! meilisearch-sdk = { version = "0.9.0", default-features = false }
+//! ```
+//!
 //! ## Run a MeiliSearch Instance <!-- omit in TOC -->
 //!
 //! This crate requires a MeiliSearch server to run.
@@ -96,6 +107,9 @@
 #![warn(clippy::all)]
 #![allow(clippy::needless_doctest_main)]
 
+// Allows the code generated by the derive macros to refer to this crate, even from inside it.
+extern crate self as meilisearch_sdk;
+
 /// Module containing the Client struct.
 pub mod client;
 /// Module containing the Document trait.
@@ -103,8 +117,14 @@ pub mod document;
 pub mod dumps;
 /// Module containing the Error struct.
 pub mod errors;
+/// Module containing typed attribute names.
+pub mod fields;
+/// Module containing a builder for filter expressions.
+pub mod filter;
 /// Module containing the Index struct.
 pub mod indexes;
+/// Module containing the API keys management.
+pub mod key;
 /// Module containing objects useful for tracking the progress of async operations.
 pub mod progress;
 mod request;
@@ -112,6 +132,9 @@ mod request;
 pub mod search;
 /// Module containing settings
 pub mod settings;
+/// Module containing the tasks API, to observe the asynchronous operations of the server.
+pub mod tasks;
+mod tenant_tokens;
 
 #[cfg(feature = "sync")]
 pub(crate) type Rc<T> = std::sync::Arc<T>;
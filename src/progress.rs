@@ -1,160 +1,81 @@
 #![allow(missing_docs)]
 
-use crate::{errors::Error, indexes::Index, request::*, Rc};
-use serde::Deserialize;
-use std::{collections::{BTreeMap, BTreeSet}, time::Duration};
-
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(crate) struct ProgressJson {
-    pub(crate) update_id: usize,
-}
-
-impl ProgressJson {
-    pub(crate) fn into_progress(self, index: &Index) -> Progress {
-        Progress {
-            id: self.update_id,
-            index_uid: Rc::clone(&index.uid),
-            host: Rc::clone(&index.host),
-            api_key: Rc::clone(&index.api_key)
+use crate::{client::Client, errors::Error, request::*, tasks::{Task, TaskInfo}, Rc};
+use std::time::Duration;
+
+impl TaskInfo {
+    /// A handle on the enqueued task, to wait for it with the host and the config it was enqueued with.
+    pub(crate) fn into_task_handle(self, host: &Rc<String>, config: &Rc<RequestConfig>) -> TaskHandle {
+        TaskHandle {
+            id: self.task_uid,
+            host: Rc::clone(host),
+            config: Rc::clone(config)
         }
     }
 }
 
-/// A struct used to track the progress of some async operations.
-pub struct Progress {
-    id: usize,
-    index_uid: Rc<String>,
+/// A handle on an asynchronous operation (a [task](../tasks/struct.Task.html)) enqueued by a write method, such as [`Index::add_documents`](../indexes/struct.Index.html#method.add_documents) or [`Index::set_settings`](../indexes/struct.Index.html#method.set_settings).
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, indexes::*, tasks::TaskStatus};
+/// # futures::executor::block_on(async move {
+/// let client = Client::new("http://localhost:7700", "masterKey");
+/// let movies = client.get_or_create("movies_task_handle").await.unwrap();
+///
+/// let task = movies.set_stop_words(["a", "the"]).await.unwrap().wait().await.unwrap();
+/// assert_eq!(task.status, TaskStatus::Succeeded);
+/// # client.delete_index("movies_task_handle").await.unwrap();
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct TaskHandle {
+    id: u64,
     host: Rc<String>,
-    api_key: Rc<String>
+    config: Rc<RequestConfig>
 }
 
-impl<'a> Progress {
-    ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
-    /// # futures::executor::block_on(async move {
-    /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let mut movies_index = client.get_or_create("movies").await.unwrap();
-    /// let progress = movies_index.delete_all_documents().await.unwrap();
-    /// let update_id = progress.get_update_id();
-    /// # client.delete_index("movies").await.unwrap();
-    /// # });
-    /// ```
-    pub fn get_update_id(&self) -> u64 {
-        self.id as u64
-    }
+/// Former name of [`TaskHandle`].
+#[deprecated(note = "Progress was renamed to TaskHandle")]
+pub type Progress = TaskHandle;
 
-    /// # Example
-    ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
-    /// # futures::executor::block_on(async move {
-    /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let mut movies_index = client.get_or_create("movies").await.unwrap();
-    /// let progress = movies_index.delete_all_documents().await.unwrap();
-    /// let status = progress.get_status().await.unwrap();
-    /// # client.delete_index("movies").await.unwrap();
-    /// # });
-    /// ```
-    pub async fn get_status(&self) -> Result<UpdateStatus, Error> {
-        request::<(), UpdateStatus>(
-            &format!(
-                "{}/indexes/{}/updates/{}",
-                self.host, self.index_uid, self.id
-            ),
-            &self.api_key,
-            Method::Get,
-            200,
-        )
-        .await
+impl<'a> TaskHandle {
+    /// The uid of the task.
+    pub fn task_uid(&self) -> u64 {
+        self.id
     }
 
-    /// Wait until MeiliSearch processes an update, and get its status.
-    /// 
-    /// `interval` = The frequency at which the server should be polled. Default = 50ms
-    /// `timeout` = The maximum time to wait for processing to complete. Default = 5000ms
-    /// 
-    /// If the waited time exceeds `timeout` then `None` will be returned.
-    /// 
-    /// # Example
-    ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, document, indexes::*, progress::*};
-    /// # use serde::{Serialize, Deserialize};
-    /// #
-    /// # #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    /// # struct Document {
-    /// #    id: usize,
-    /// #    value: String,
-    /// #    kind: String,
-    /// # }
-    /// # 
-    /// # impl document::Document for Document {
-    /// #    type UIDType = usize;
-    /// #
-    /// #    fn get_uid(&self) -> &Self::UIDType {
-    /// #        &self.id
-    /// #    }
-    /// # }
-    /// #
-    /// # futures::executor::block_on(async move {
-    /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let movies = client.create_index("movies_wait_for_pending", None).await.unwrap();
-    /// 
-    /// let progress = movies.add_documents(&[
-    ///     Document { id: 0, kind: "title".into(), value: "The Social Network".to_string() },
-    ///     Document { id: 1, kind: "title".into(), value: "Harry Potter and the Sorcerer's Stone".to_string() },
-    /// ], None).await.unwrap();
-    /// 
-    /// let status = progress.wait_for_pending_update(None, None).await.unwrap();
-    /// 
-    /// # client.delete_index("movies_wait_for_pending").await.unwrap();
-    /// assert!(matches!(status.unwrap(), UpdateStatus::Processed { .. }));
-    /// # });
-    /// ```
-    pub async fn wait_for_pending_update(
-        &self,
-        interval: Option<Duration>,
-        timeout: Option<Duration>,
-    ) -> Option<Result<UpdateStatus, Error>> {
-        let interval = interval.unwrap_or_else(|| Duration::from_millis(50));
-        let timeout = timeout.unwrap_or_else(|| Duration::from_millis(5000));
+    /// Get the current state of the task.
+    pub async fn get_task(&self) -> Result<Task, Error> {
+        self.client().get_task(self.task_uid()).await
+    }
 
-        let mut elapsed_time = Duration::new(0, 0);
-        let mut status_result: Result<UpdateStatus, Error>;
+    /// Wait until the task is finished, and return it.
+    /// See [`Client::wait_for_task`](../client/struct.Client.html#method.wait_for_task) for the default interval and timeout.
+    pub async fn wait(&self) -> Result<Task, Error> {
+        self.wait_with(None, None).await
+    }
 
-        while timeout > elapsed_time {
-            status_result = self.get_status().await;
+    /// Wait until the task is finished, with a custom interval and timeout, and return it.
+    /// See [`Client::wait_for_task`](../client/struct.Client.html#method.wait_for_task).
+    pub async fn wait_with(&self, interval: Option<Duration>, timeout: Option<Duration>) -> Result<Task, Error> {
+        self.client().wait_for_task(self.task_uid(), interval, timeout).await
+    }
 
-            match status_result {
-                Ok (status) => {
-                    match status {
-                        UpdateStatus::Failed { .. } | UpdateStatus::Processed { .. } => {
-                            return Some(self.get_status().await);
-                        },
-                        UpdateStatus::Enqueued { .. } => {
-                            elapsed_time += interval;
-                            async_sleep(interval).await;
-                        },
-                    }
-                },
-                Err (error) => return Some(Err(error)),
-            };
+    fn client(&self) -> Client {
+        Client {
+            host: Rc::clone(&self.host),
+            config: Rc::clone(&self.config),
+            compression: None,
         }
-
-        None
     }
 }
 
+/// Sleep with the global timer thread of `futures-timer` rather than with the timer of a runtime, so that the SDK can be used with any executor.
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) async fn async_sleep(interval: Duration) {
-    let (sender, receiver) = futures::channel::oneshot::channel::<()>();
-    std::thread::spawn(move || {
-        std::thread::sleep(interval);
-        let _ = sender.send(());
-    });
-    let _ = receiver.await;
+    futures_timer::Delay::new(interval).await;
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -173,88 +94,6 @@ pub(crate) async fn async_sleep(interval: Duration) {
     })).await.unwrap();
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub enum RankingRule {
-    Typo,
-    Words,
-    Proximity,
-    Attribute,
-    WordsPosition,
-    Exactness,
-    Asc(String),
-    Desc(String),
-}
-
-#[derive(Debug, Clone, Deserialize)]
-pub enum UpdateState<T> {
-    Update(T),
-    Clear,
-    Nothing,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-pub struct SettingsUpdate {
-    pub ranking_rules: UpdateState<Vec<RankingRule>>,
-    pub distinct_attribute: UpdateState<String>,
-    pub searchable_attributes: UpdateState<Vec<String>>,
-    pub displayed_attributes: UpdateState<BTreeSet<String>>,
-    pub stop_words: UpdateState<BTreeSet<String>>,
-    pub synonyms: UpdateState<BTreeMap<String, Vec<String>>>,
-    pub attributes_for_faceting: UpdateState<Vec<String>>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "name")]
-pub enum UpdateType {
-    ClearAll,
-    Customs,
-    DocumentsAddition { number: usize },
-    DocumentsPartial { number: usize },
-    DocumentsDeletion { number: usize },
-    Settings { settings: Box<SettingsUpdate> },
-}
-
-#[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct ProcessedUpdateResult {
-    pub update_id: u64,
-    #[serde(rename = "type")]
-    pub update_type: UpdateType,
-    pub error: Option<String>,
-    pub error_type: Option<String>,
-    pub error_code: Option<String>,
-    pub error_link: Option<String>,
-    pub duration: f64,        // in seconds
-    pub enqueued_at: String,  // TODO deserialize to datetime
-    pub processed_at: String, // TODO deserialize to datetime
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct EnqueuedUpdateResult {
-    pub update_id: u64,
-    #[serde(rename = "type")]
-    pub update_type: UpdateType,
-    pub enqueued_at: String, // TODO deserialize to datetime
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase", tag = "status")]
-pub enum UpdateStatus {
-    Enqueued {
-        #[serde(flatten)]
-        content: EnqueuedUpdateResult,
-    },
-    Failed {
-        #[serde(flatten)]
-        content: ProcessedUpdateResult,
-    },
-    Processed {
-        #[serde(flatten)]
-        content: ProcessedUpdateResult,
-    },
-}
-
 #[cfg(test)]
 mod test {
     use crate::{client::*, document, progress::*};
@@ -278,51 +117,22 @@ mod test {
     }
 
     #[async_test]
-    async fn test_wait_for_pending_updates_with_args() {
+    async fn test_task_handle_wait() {
         let client = Client::new("http://localhost:7700", "masterKey");
-        let movies = client.get_or_create("movies_wait_for_pending_args").await.unwrap();
-        let progress = movies.add_documents(&[
+        let movies = client.get_or_create("movies_task_handle_wait").await.unwrap();
+        let task_handle = movies.add_documents(&[
             Document {
                 id: 0,
                 kind: "title".into(),
                 value: "The Social Network".to_string(),
             },
-            Document {
-                id: 1,
-                kind: "title".into(),
-                value: "Harry Potter and the Sorcerer's Stone".to_string(),
-            },
         ], None).await.unwrap();
-        let status = progress.wait_for_pending_update(
-            Some(Duration::from_millis(1)), Some(Duration::from_millis(6000))
-        ).await.unwrap();
-    
-        client.delete_index("movies_wait_for_pending_args").await.unwrap();
-        assert!(matches!(status.unwrap(), UpdateStatus::Processed { .. }));
-    }
+        let task = task_handle.wait_with(Some(Duration::from_millis(1)), Some(Duration::from_secs(10))).await.unwrap();
+        assert_eq!(task.uid, task_handle.task_uid());
+        assert_eq!(task.status, crate::tasks::TaskStatus::Succeeded);
+        assert_eq!(task_handle.get_task().await.unwrap(), task);
 
-    #[async_test]
-    async fn test_wait_for_pending_updates_time_out() {
-        let client = Client::new("http://localhost:7700", "masterKey");
-        let movies = client.get_or_create("movies_wait_for_pending_timeout").await.unwrap();
-        let progress = movies.add_documents(&[
-            Document {
-                id: 0,
-                kind: "title".into(),
-                value: "The Social Network".to_string(),
-            },
-            Document {
-                id: 1,
-                kind: "title".into(),
-                value: "Harry Potter and the Sorcerer's Stone".to_string(),
-            },
-        ], None).await.unwrap();
-        let status = progress.wait_for_pending_update(
-            Some(Duration::from_millis(1)), Some(Duration::from_nanos(1))
-        ).await;
-    
-        client.delete_index("movies_wait_for_pending_timeout").await.unwrap();
-        assert_eq!(status.is_none(), true);
+        client.delete_index("movies_task_handle_wait").await.unwrap();
     }
 
     #[async_test]
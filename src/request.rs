@@ -1,52 +1,679 @@
-use crate::errors::Error;
+use crate::{client::{CancellationToken, Compression, MetricsObserver, Middleware, MiddlewareRequest, MiddlewareResponse, RateLimit, RequestMetrics, RetryPolicy}, errors::{Error, MeilisearchError}, Rc};
 use log::{error, trace, warn};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{from_str, to_string};
+use std::time::Duration;
+
+/// The settings shared by the requests of a [`Client`](crate::client::Client), and of the indexes and tasks obtained from it.
+#[derive(Debug, Clone)]
+pub(crate) struct RequestConfig {
+    pub(crate) api_key: String,
+    /// The HTTP client sending the requests, instead of the default one of isahc
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) http_client: Option<isahc::HttpClient>,
+    /// The maximum duration of a request, until its response is received
+    pub(crate) timeout: Option<Duration>,
+    /// The maximum duration of the connection to the server
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) connect_timeout: Option<Duration>,
+    /// The Unix socket to connect to, instead of the host of the URLs
+    #[cfg(unix)]
+    pub(crate) unix_socket: Option<std::path::PathBuf>,
+    /// Whether the browser sends cookies and HTTP authentication with the requests
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) credentials: Option<web_sys::RequestCredentials>,
+    /// Whether the requests can be cross-origin
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) mode: Option<web_sys::RequestMode>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    /// The headers added to every request
+    pub(crate) headers: Vec<(String, String)>,
+    /// Appended to the `User-Agent` of the SDK
+    pub(crate) user_agent: Option<String>,
+    pub(crate) middlewares: Vec<Rc<dyn Middleware>>,
+    pub(crate) metrics_observer: Option<Rc<dyn MetricsObserver>>,
+    pub(crate) cancellation_token: Option<CancellationToken>,
+    /// The primary host and its replicas, shared by the copies of the config
+    pub(crate) host_pool: Option<Rc<HostPool>>,
+    /// The rate limiter shared by the copies of the config
+    pub(crate) rate_limiter: Option<Rc<RateLimiter>>,
+}
+
+impl RequestConfig {
+    pub(crate) fn new(api_key: String) -> RequestConfig {
+        RequestConfig {
+            api_key,
+            #[cfg(not(target_arch = "wasm32"))]
+            http_client: None,
+            timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            connect_timeout: None,
+            #[cfg(unix)]
+            unix_socket: None,
+            #[cfg(target_arch = "wasm32")]
+            credentials: None,
+            #[cfg(target_arch = "wasm32")]
+            mode: None,
+            retry_policy: None,
+            headers: Vec::new(),
+            user_agent: None,
+            middlewares: Vec::new(),
+            metrics_observer: None,
+            cancellation_token: None,
+            host_pool: None,
+            rate_limiter: None,
+        }
+    }
+
+    /// The `User-Agent` of the SDK, followed by the one of the application, if any.
+    pub(crate) fn user_agent(&self) -> String {
+        let sdk = concat!("Meilisearch Rust (v", env!("CARGO_PKG_VERSION"), ")");
+        match &self.user_agent {
+            Some(user_agent) => format!("{}; {}", sdk, user_agent),
+            None => sdk.to_string(),
+        }
+    }
+}
+
+/// A primary host and its replicas, remembering which hosts recently failed.
+#[derive(Debug)]
+pub(crate) struct HostPool {
+    /// The primary host, followed by the replicas
+    hosts: Vec<String>,
+    /// The position of the host to try first for the next read request
+    next: std::sync::atomic::AtomicUsize,
+    /// Until when each host is considered down, in milliseconds since the Unix epoch
+    down_until: std::sync::Mutex<Vec<f64>>,
+}
+
+impl HostPool {
+    /// How long a host is avoided after failing, in milliseconds
+    const DOWN_DURATION: f64 = 30_000.0;
+
+    pub(crate) fn new(primary: String, replicas: Vec<String>) -> HostPool {
+        let mut hosts = vec![primary];
+        hosts.extend(replicas);
+        HostPool {
+            next: std::sync::atomic::AtomicUsize::new(0),
+            down_until: std::sync::Mutex::new(vec![0.0; hosts.len()]),
+            hosts,
+        }
+    }
+
+    /// The URLs of a read request on each host, starting with a different host each time to balance the load, and with the hosts that recently failed last.
+    fn urls(&self, url: &str) -> Vec<(Option<usize>, String)> {
+        let path = match url.strip_prefix(self.hosts[0].as_str()) {
+            Some(path) => path,
+            None => return vec![(None, url.to_string())],
+        };
+        let start = self.next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let now = now_millis();
+        let down_until = self.down_until.lock().unwrap();
+        let (mut hosts, down): (Vec<usize>, Vec<usize>) = (0..self.hosts.len())
+            .map(|i| (start + i) % self.hosts.len())
+            .partition(|&host| down_until[host] <= now);
+        hosts.extend(down);
+        hosts.into_iter().map(|host| (Some(host), format!("{}{}", self.hosts[host], path))).collect()
+    }
+
+    fn set_healthy(&self, host: usize, healthy: bool) {
+        self.down_until.lock().unwrap()[host] = if healthy { 0.0 } else { now_millis() + HostPool::DOWN_DURATION };
+    }
+}
+
+/// A token bucket, holding up to `burst` tokens and refilled with `requests_per_second` tokens per second.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    rate_limit: RateLimit,
+    /// The available tokens, and when they were counted, in milliseconds since the Unix epoch
+    bucket: std::sync::Mutex<(f64, f64)>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit: RateLimit) -> RateLimiter {
+        RateLimiter {
+            // The fields of the rate limit are public, so it may not have been built with `RateLimit::new`
+            rate_limit: RateLimit::new(rate_limit.requests_per_second, rate_limit.burst),
+            bucket: std::sync::Mutex::new((rate_limit.burst as f64, now_millis())),
+        }
+    }
+
+    /// Wait until a request can be sent, and take its token.
+    async fn acquire(&self) {
+        while let Err(wait) = self.try_acquire(now_millis()) {
+            crate::progress::async_sleep(wait).await;
+        }
+    }
+
+    /// Take a token at `now`, in milliseconds since the Unix epoch, or return how long to wait for the next one.
+    fn try_acquire(&self, now: f64) -> Result<(), Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        let (tokens, counted_at) = *bucket;
+        let refilled = (now - counted_at).max(0.0) / 1000.0 * self.rate_limit.requests_per_second;
+        let tokens = (tokens + refilled).min(self.rate_limit.burst.max(1) as f64);
+        if tokens >= 1.0 {
+            *bucket = (tokens - 1.0, now);
+            return Ok(());
+        }
+        *bucket = (tokens, now);
+        Err(Duration::from_secs_f64((1.0 - tokens) / self.rate_limit.requests_per_second))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_millis() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |duration| duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now_millis() -> f64 {
+    js_sys::Date::now()
+}
 
 #[derive(Debug)]
-pub(crate) enum Method<T: Serialize> {
+pub(crate) enum Method<T> {
     Get,
     Post(T),
     Put(T),
+    Patch(T),
     Delete,
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+impl<T> Method<T> {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post(_) => "POST",
+            Method::Put(_) => "PUT",
+            Method::Patch(_) => "PATCH",
+            Method::Delete => "DELETE",
+        }
+    }
+
+    /// Whether sending the request several times has the same effect as sending it once, so that it can be retried.
+    pub(crate) fn is_idempotent(&self) -> bool {
+        matches!(self, Method::Get | Method::Put(_) | Method::Delete)
+    }
+}
+
+/// An already encoded request body, sent as is with its own content type instead of being serialized to JSON.
+/// If `compression` is set, the body is compressed before being sent.
+pub(crate) struct Payload<'a> {
+    pub(crate) content_type: &'a str,
+    pub(crate) data: std::borrow::Cow<'a, [u8]>,
+    pub(crate) compression: Option<Compression>,
+}
+
+impl<'a> std::fmt::Debug for Payload<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} bytes of {}", self.data.len(), self.content_type)?;
+        match self.compression {
+            Some(compression) => write!(f, " ({})", compression.content_encoding()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Send a request with a JSON body, or without body.
 pub(crate) async fn request<Input: Serialize + std::fmt::Debug, Output: 'static + DeserializeOwned>(
     url: &str,
-    apikey: &str,
+    config: &RequestConfig,
+    method: Method<Input>,
+    expected_status_code: u16
+) -> Result<Output, Error> {
+    traced(url, method.as_str(), cancellable(config, send_request(url, config, method, expected_status_code))).await
+}
+
+/// Send a request whose body is already serialized.
+pub(crate) async fn request_payload<Output: 'static + DeserializeOwned>(
+    url: &str,
+    config: &RequestConfig,
+    method: Method<Payload<'_>>,
+    expected_status_code: u16
+) -> Result<Output, Error> {
+    traced(url, method.as_str(), cancellable(config, send_request_payload(url, config, method, expected_status_code))).await
+}
+
+/// Send a request whose body is read from a stream.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn request_stream<Output: 'static + DeserializeOwned>(
+    url: &str,
+    config: &RequestConfig,
+    method: Method<StreamPayload<'_>>,
+    expected_status_code: u16
+) -> Result<Output, Error> {
+    traced(url, method.as_str(), cancellable(config, send_request_stream(url, config, method, expected_status_code))).await
+}
+
+/// Run a request until it completes or the cancellation token of the config is cancelled.
+/// The request is dropped once cancelled, which aborts it.
+async fn cancellable<Output>(
+    config: &RequestConfig,
+    request: impl std::future::Future<Output = Result<Output, Error>>,
+) -> Result<Output, Error> {
+    use futures::future::{select, Either};
+
+    match &config.cancellation_token {
+        Some(cancellation_token) if cancellation_token.is_cancelled() => Err(Error::Cancelled),
+        Some(cancellation_token) => match select(Box::pin(request), Box::pin(cancellation_token.cancelled())).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(Error::Cancelled),
+        },
+        None => request.await,
+    }
+}
+
+/// Run a request in a `meilisearch.request` span, recording its method, endpoint and index uid, and then the status and the duration of its response.
+#[cfg(feature = "tracing")]
+async fn traced<Output>(
+    url: &str,
+    method: &'static str,
+    request: impl std::future::Future<Output = Result<Output, Error>>,
+) -> Result<Output, Error> {
+    use tracing::{field::Empty, Instrument};
+
+    let (endpoint, index_uid) = endpoint(url);
+    let span = tracing::debug_span!(
+        "meilisearch.request",
+        method,
+        endpoint,
+        index_uid,
+        status = Empty,
+        duration_ms = Empty
+    );
+    let result = request.instrument(span.clone()).await;
+    if let Err(e) = &result {
+        span.in_scope(|| tracing::debug!(error = %e, "Request failed"));
+    }
+    result
+}
+
+#[cfg(not(feature = "tracing"))]
+async fn traced<Output>(
+    _url: &str,
+    _method: &'static str,
+    request: impl std::future::Future<Output = Result<Output, Error>>,
+) -> Result<Output, Error> {
+    request.await
+}
+
+/// Record the status and the duration of a response in the current span.
+#[cfg(feature = "tracing")]
+fn record_response(response: &MiddlewareResponse) {
+    let span = tracing::Span::current();
+    let duration_ms = response.duration.as_secs_f64() * 1000.0;
+    if let Some(status) = response.status {
+        span.record("status", &status);
+    }
+    span.record("duration_ms", &duration_ms);
+    tracing::debug!(status = ?response.status, duration_ms, "Received response");
+}
+
+/// The path of a URL, without its query string, and the uid of the index it targets, if any.
+fn endpoint(url: &str) -> (&str, Option<&str>) {
+    let path = match url.find("://") {
+        Some(scheme_end) => url[scheme_end + 3..].find('/').map_or("/", |start| &url[scheme_end + 3 + start..]),
+        None => url,
+    };
+    let path = path.split('?').next().unwrap_or(path);
+    let index_uid = path
+        .find("/indexes/")
+        .and_then(|start| path[start + 9..].split('/').next())
+        .filter(|uid| !uid.is_empty());
+    (path, index_uid)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_request<Input: Serialize + std::fmt::Debug, Output: 'static + DeserializeOwned>(
+    url: &str,
+    config: &RequestConfig,
     method: Method<Input>,
     expected_status_code: u16
 ) -> Result<Output, Error> {
-    use isahc::*;
+    use isahc::AsyncBody;
+
+    trace!("{:?} on {}", method, url);
+
+    let body = match &method {
+        Method::Post(body) | Method::Put(body) | Method::Patch(body) => Some(to_string(&body).unwrap()),
+        Method::Get | Method::Delete => None,
+    };
+    let response = send(url, config, &method, |url| {
+        let builder = request_builder(url, config, &method);
+        match &body {
+            Some(body) => builder
+                .header("Content-Type", "application/json")
+                .body(AsyncBody::from(body.clone())),
+            None => builder.body(AsyncBody::empty()),
+        }
+        .map_err(|_| crate::errors::Error::InvalidRequest)
+    }).await?;
+
+    read_response(response, expected_status_code).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_request_payload<Output: 'static + DeserializeOwned>(
+    url: &str,
+    config: &RequestConfig,
+    method: Method<Payload<'_>>,
+    expected_status_code: u16
+) -> Result<Output, Error> {
+    use isahc::AsyncBody;
+
+    trace!("{:?} on {}", method, url);
+
+    let response = send(url, config, &method, |url| {
+        let builder = request_builder(url, config, &method);
+        match &method {
+            Method::Post(payload) | Method::Put(payload) | Method::Patch(payload) => payload_request(
+                builder,
+                payload.content_type,
+                payload.compression,
+                AsyncBody::from(payload.data.to_vec()),
+            ),
+            Method::Get | Method::Delete => builder
+                .body(AsyncBody::empty())
+                .map_err(|_| crate::errors::Error::InvalidRequest),
+        }
+    }).await?;
+
+    read_response(response, expected_status_code).await
+}
+
+/// A request body read progressively while it is sent, instead of being held in memory.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct StreamPayload<'a> {
+    pub(crate) content_type: &'a str,
+    pub(crate) body: isahc::AsyncBody,
+    pub(crate) compression: Option<Compression>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<'a> std::fmt::Debug for StreamPayload<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.body.len() {
+            Some(len) => write!(f, "{} bytes of {}", len, self.content_type)?,
+            None => write!(f, "stream of {}", self.content_type)?,
+        }
+        match self.compression {
+            Some(compression) => write!(f, " ({})", compression.content_encoding()),
+            None => Ok(()),
+        }
+    }
+}
 
+/// Unlike the other requests, it is never retried since the stream can only be read once.
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_request_stream<Output: 'static + DeserializeOwned>(
+    url: &str,
+    config: &RequestConfig,
+    method: Method<StreamPayload<'_>>,
+    expected_status_code: u16
+) -> Result<Output, Error> {
     trace!("{:?} on {}", method, url);
 
-    let mut response = match &method {
-        Method::Get => Request::get(url)
-            .header("X-Meili-API-Key", apikey)
-            .body(())
-            .map_err(|_| crate::errors::Error::InvalidRequest)?
-            .send_async().await?,
-        Method::Delete => Request::delete(url)
-            .header("X-Meili-API-Key", apikey)
-            .body(())
-            .map_err(|_| crate::errors::Error::InvalidRequest)?
-            .send_async().await?,
-        Method::Post(body) => Request::post(url)
-            .header("X-Meili-API-Key", apikey)
-            .header("Content-Type", "application/json")
-            .body(to_string(&body).unwrap())
-            .map_err(|_| crate::errors::Error::InvalidRequest)?
-            .send_async().await?,
-        Method::Put(body) => Request::put(url)
-            .header("X-Meili-API-Key", apikey)
-            .header("Content-Type", "application/json")
-            .body(to_string(&body).unwrap())
-            .map_err(|_| crate::errors::Error::InvalidRequest)?
-            .send_async().await?,
+    let builder = request_builder(url, config, &method);
+    let request = match method {
+        Method::Post(payload) | Method::Put(payload) | Method::Patch(payload) => {
+            payload_request(builder, payload.content_type, payload.compression, payload.body)?
+        }
+        Method::Get | Method::Delete => builder
+            .body(isahc::AsyncBody::empty())
+            .map_err(|_| crate::errors::Error::InvalidRequest)?,
+    };
+    let response = send_once(config, request).await?;
+
+    read_response(response, expected_status_code).await
+}
+
+/// Start building a request with the method and the headers shared by all requests.
+#[cfg(not(target_arch = "wasm32"))]
+fn request_builder<T>(url: &str, config: &RequestConfig, method: &Method<T>) -> isahc::http::request::Builder {
+    use isahc::{config::Configurable, Request};
+
+    let mut builder = match method {
+        Method::Get => Request::get(url),
+        Method::Delete => Request::delete(url),
+        Method::Post(_) => Request::post(url),
+        Method::Put(_) => Request::put(url),
+        Method::Patch(_) => Request::patch(url),
+    }
+    .header("Authorization", format!("Bearer {}", config.api_key))
+    .header("User-Agent", config.user_agent());
+    for (name, value) in &config.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    if let Some(timeout) = config.timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(connect_timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    #[cfg(unix)]
+    if let Some(unix_socket) = &config.unix_socket {
+        builder = builder.unix_socket(unix_socket.as_path());
+    }
+    // Brotli is decompressed by the SDK, since libcurl is usually built without it
+    #[cfg(feature = "compression")]
+    {
+        builder = builder.automatic_decompression(false).header("Accept-Encoding", "gzip, deflate, br");
+    }
+    builder
+}
+
+/// Finish building a request with an encoded body, compressing it if needed.
+#[cfg(not(target_arch = "wasm32"))]
+fn payload_request(
+    builder: isahc::http::request::Builder,
+    content_type: &str,
+    compression: Option<Compression>,
+    body: isahc::AsyncBody,
+) -> Result<isahc::Request<isahc::AsyncBody>, Error> {
+    let builder = builder.header("Content-Type", content_type);
+    let (builder, body) = match compression {
+        Some(compression) => (
+            builder.header("Content-Encoding", compression.content_encoding()),
+            compress_stream(compression, body),
+        ),
+        None => (builder, body),
+    };
+    builder.body(body).map_err(|_| crate::errors::Error::InvalidRequest)
+}
+
+/// Send the requests built by `build` until one succeeds or can't be retried, according to the retry policy of the config.
+/// Read requests are sent to the other hosts of the config, if any, when a host fails.
+#[cfg(not(target_arch = "wasm32"))]
+async fn send<T>(
+    url: &str,
+    config: &RequestConfig,
+    method: &Method<T>,
+    mut build: impl FnMut(&str) -> Result<isahc::Request<isahc::AsyncBody>, Error>,
+) -> Result<isahc::Response<isahc::AsyncBody>, Error> {
+    let mut attempt = 1;
+    loop {
+        let mut result = Err(Error::UnreachableServer);
+        for (host, url) in host_urls(url, config, method.as_str()) {
+            result = send_once(config, build(&url)?).await;
+            let failed = match &result {
+                Ok(response) => is_transient_status(response.status().as_u16()),
+                Err(e) => is_host_error(e),
+            };
+            if let (Some(host_pool), Some(host)) = (&config.host_pool, host) {
+                host_pool.set_healthy(host, !failed);
+            }
+            if !failed {
+                break;
+            }
+        }
+        let retry = match &result {
+            Ok(response) => is_transient_status(response.status().as_u16()) && method.is_idempotent(),
+            // The request didn't reach the server
+            Err(Error::UnreachableServer) => true,
+            Err(Error::Timeout) | Err(Error::HttpError(_)) => method.is_idempotent(),
+            Err(_) => false,
+        };
+        match config.retry_policy {
+            Some(retry_policy) if retry && attempt < retry_policy.max_attempts => {
+                let delay = retry_policy.delay(attempt);
+                warn!("Attempt {} failed, retrying in {:?}", attempt, delay);
+                crate::progress::async_sleep(delay).await;
+                attempt += 1;
+            }
+            _ => return result,
+        }
+    }
+}
+
+/// Send a request with the HTTP client of the config, if any, calling the middlewares around it.
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_once(
+    config: &RequestConfig,
+    mut request: isahc::Request<isahc::AsyncBody>,
+) -> Result<isahc::Response<isahc::AsyncBody>, Error> {
+    use isahc::{http::header::{HeaderName, HeaderValue}, RequestExt};
+
+    if let Some(rate_limiter) = &config.rate_limiter {
+        rate_limiter.acquire().await;
+    }
+
+    let method = request.method().to_string();
+    let url = request.uri().to_string();
+    let mut middleware_request = MiddlewareRequest {
+        method: &method,
+        url: &url,
+        headers: Vec::new(),
+    };
+    for middleware in &config.middlewares {
+        middleware.on_request(&mut middleware_request);
+    }
+    for (name, value) in &middleware_request.headers {
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| Error::InvalidRequest)?;
+        let value = HeaderValue::from_str(value).map_err(|_| Error::InvalidRequest)?;
+        request.headers_mut().insert(name, value);
+    }
+
+    let request_size = request.body().len();
+    let start = std::time::Instant::now();
+    let result = match &config.http_client {
+        Some(http_client) => http_client.send_async(request).await,
+        None => request.send_async().await,
+    };
+
+    let middleware_response = MiddlewareResponse {
+        status: result.as_ref().ok().map(|response| response.status().as_u16()),
+        duration: start.elapsed(),
+    };
+    #[cfg(feature = "tracing")]
+    record_response(&middleware_response);
+    for middleware in &config.middlewares {
+        middleware.on_response(&middleware_request, &middleware_response);
+    }
+    if let Some(metrics_observer) = &config.metrics_observer {
+        let (endpoint, index_uid) = endpoint(&url);
+        metrics_observer.observe(&RequestMetrics {
+            method: &method,
+            endpoint,
+            index_uid,
+            status: middleware_response.status,
+            duration: middleware_response.duration,
+            request_size,
+            response_size: result.as_ref().ok().and_then(|response| response.body().len()),
+        });
+    }
+    Ok(result?)
+}
+
+/// Whether an error means that the host may be down.
+fn is_host_error(error: &Error) -> bool {
+    matches!(error, Error::UnreachableServer | Error::Timeout | Error::HttpError(_))
+}
+
+/// Whether a request only searches or reads documents, and can be sent to a replica.
+///
+/// Other reads, such as the tasks, the keys, the indexes and their settings, are sent to the primary so that they see its writes:
+/// replicas have their own tasks, and may not have processed the latest ones yet.
+fn is_read(method: &str, url: &str) -> bool {
+    let (endpoint, index_uid) = endpoint(url);
+    let index_endpoint = match (endpoint.find("/indexes/"), index_uid) {
+        (Some(start), Some(index_uid)) => &endpoint[start + 9 + index_uid.len()..],
+        _ => "",
     };
+    method == "GET" && (index_endpoint == "/search" || index_endpoint == "/documents" || index_endpoint.starts_with("/documents/"))
+        || method == "POST"
+            && ["/search", "/multi-search", "/facet-search", "/documents/fetch"]
+                .iter()
+                .any(|suffix| endpoint.ends_with(suffix))
+}
+
+/// The URLs to send a request to, with the position of their host in the host pool of the config, if any.
+fn host_urls(url: &str, config: &RequestConfig, method: &str) -> Vec<(Option<usize>, String)> {
+    match &config.host_pool {
+        Some(host_pool) if is_read(method, url) => host_pool.urls(url),
+        _ => vec![(None, url.to_string())],
+    }
+}
+
+/// Whether a response status means that the server, or a proxy in front of it, is temporarily unable to process requests.
+fn is_transient_status(status: u16) -> bool {
+    matches!(status, 502 | 503 | 504)
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "compression"))]
+fn compress_stream(compression: Compression, body: isahc::AsyncBody) -> isahc::AsyncBody {
+    use async_compression::futures::bufread::{BrotliEncoder, GzipEncoder, ZlibEncoder};
+    use futures::io::BufReader;
+    use isahc::AsyncBody;
+
+    let body = BufReader::new(body);
+    match compression {
+        Compression::Gzip => AsyncBody::from_reader(GzipEncoder::new(body)),
+        // HTTP's deflate is the zlib format, not a raw deflate stream
+        Compression::Deflate => AsyncBody::from_reader(ZlibEncoder::new(body)),
+        Compression::Brotli => AsyncBody::from_reader(BrotliEncoder::new(body)),
+    }
+}
+
+/// Decompress the body of a response according to its `Content-Encoding`.
+#[cfg(all(not(target_arch = "wasm32"), feature = "compression"))]
+fn decompress_response(response: isahc::Response<isahc::AsyncBody>) -> isahc::Response<isahc::AsyncBody> {
+    use async_compression::futures::bufread::{BrotliDecoder, GzipDecoder, ZlibDecoder};
+    use futures::io::BufReader;
+    use isahc::AsyncBody;
+
+    let content_encoding = response
+        .headers()
+        .get("Content-Encoding")
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_ascii_lowercase());
+    response.map(|body| match content_encoding.as_deref() {
+        Some("gzip") => AsyncBody::from_reader(GzipDecoder::new(BufReader::new(body))),
+        Some("deflate") => AsyncBody::from_reader(ZlibDecoder::new(BufReader::new(body))),
+        Some("br") => AsyncBody::from_reader(BrotliDecoder::new(BufReader::new(body))),
+        _ => body,
+    })
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "compression")))]
+fn decompress_response(response: isahc::Response<isahc::AsyncBody>) -> isahc::Response<isahc::AsyncBody> {
+    response
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "compression")))]
+fn compress_stream(_compression: Compression, _body: isahc::AsyncBody) -> isahc::AsyncBody {
+    unreachable!("compression can only be enabled with the compression feature")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_response<Output: 'static + DeserializeOwned>(
+    response: isahc::Response<isahc::AsyncBody>,
+    expected_status_code: u16
+) -> Result<Output, Error> {
+    use isahc::AsyncReadResponseExt;
 
+    let mut response = decompress_response(response);
     let status = response.status().as_u16();
     let mut body = response.text().await.map_err(|e| crate::errors::Error::HttpError(e.into()))?;
     if body.is_empty() {
@@ -57,67 +684,176 @@ pub(crate) async fn request<Input: Serialize + std::fmt::Debug, Output: 'static
 }
 
 #[cfg(target_arch = "wasm32")]
-pub(crate) async fn request<Input: Serialize + std::fmt::Debug, Output: 'static + DeserializeOwned>(
+async fn send_request<Input: Serialize + std::fmt::Debug, Output: 'static + DeserializeOwned>(
     url: &str,
-    apikey: &str,
+    config: &RequestConfig,
     method: Method<Input>,
     expected_status_code: u16
 ) -> Result<Output, Error> {
     use wasm_bindgen::JsValue;
-    use web_sys::{Headers, RequestInit, Response};
-    use wasm_bindgen_futures::JsFuture;
 
     trace!("{:?} on {}", method, url);
 
-    // The 2 following unwraps should not be able to fail
-
-    let headers = Headers::new().unwrap();
-    headers.append("X-Meili-API-Key", apikey).unwrap();
-
-    let mut request: RequestInit = RequestInit::new();
-    request.headers(&headers);
+    let headers = headers(config)?;
 
+    let mut request = request_init(config, &headers);
+    request.method(method.as_str());
     match &method {
-        Method::Get => {
-            request.method("GET");
-        }
-        Method::Delete => {
-            request.method("DELETE");
-        }
-        Method::Post(body) => {
-            request.method("POST");
-            headers.append("Content-Type", "application/json").unwrap();
-            request.body(Some(&JsValue::from_str(&to_string(body).unwrap())));
-        }
-        Method::Put(body) => {
-            request.method("PUT");
+        Method::Post(body) | Method::Put(body) | Method::Patch(body) => {
             headers.append("Content-Type", "application/json").unwrap();
             request.body(Some(&JsValue::from_str(&to_string(body).unwrap())));
         }
+        Method::Get | Method::Delete => (),
+    }
+
+    fetch(url, config, &mut request, &headers, method.as_str(), method.is_idempotent(), expected_status_code).await
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn send_request_payload<Output: 'static + DeserializeOwned>(
+    url: &str,
+    config: &RequestConfig,
+    method: Method<Payload<'_>>,
+    expected_status_code: u16
+) -> Result<Output, Error> {
+    trace!("{:?} on {}", method, url);
+
+    let headers = headers(config)?;
+
+    let mut request = request_init(config, &headers);
+    request.method(method.as_str());
+    let method_name = method.as_str();
+    let idempotent = method.is_idempotent();
+    let payload = match method {
+        Method::Post(payload) | Method::Put(payload) | Method::Patch(payload) => Some(payload),
+        Method::Get | Method::Delete => None,
+    };
+    if let Some(payload) = payload {
+        headers.append("Content-Type", payload.content_type).unwrap();
+        let data = match payload.compression {
+            Some(compression) => {
+                headers.append("Content-Encoding", compression.content_encoding()).unwrap();
+                std::borrow::Cow::Owned(compress(compression, &payload.data).await)
+            }
+            None => payload.data,
+        };
+        request.body(Some(&js_sys::Uint8Array::from(data.as_ref()).into()));
+    }
+
+    fetch(url, config, &mut request, &headers, method_name, idempotent, expected_status_code).await
+}
+
+/// The headers shared by all requests.
+/// Browsers don't let the `User-Agent` be changed, so the SDK identifies itself with the `X-Meilisearch-Client` header instead.
+#[cfg(target_arch = "wasm32")]
+fn headers(config: &RequestConfig) -> Result<web_sys::Headers, Error> {
+    // This unwrap should not be able to fail
+    let headers = web_sys::Headers::new().unwrap();
+    let append = |name: &str, value: &str| headers.append(name, value).map_err(|_| Error::InvalidRequest);
+    append("Authorization", &format!("Bearer {}", config.api_key))?;
+    append("X-Meilisearch-Client", &config.user_agent())?;
+    for (name, value) in &config.headers {
+        append(name, value)?;
     }
+    Ok(headers)
+}
+
+/// Start building a request with the headers and the fetch options of the config.
+#[cfg(target_arch = "wasm32")]
+fn request_init(config: &RequestConfig, headers: &web_sys::Headers) -> web_sys::RequestInit {
+    let mut request = web_sys::RequestInit::new();
+    request.headers(headers);
+    if let Some(credentials) = config.credentials {
+        request.credentials(credentials);
+    }
+    if let Some(mode) = config.mode {
+        request.mode(mode);
+    }
+    request
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "compression"))]
+async fn compress(compression: Compression, data: &[u8]) -> Vec<u8> {
+    use async_compression::futures::bufread::{BrotliEncoder, GzipEncoder, ZlibEncoder};
+    use futures::io::AsyncReadExt;
+
+    // Reading from a slice never fails
+    let mut compressed = Vec::new();
+    match compression {
+        Compression::Gzip => GzipEncoder::new(data).read_to_end(&mut compressed).await.unwrap(),
+        // HTTP's deflate is the zlib format, not a raw deflate stream
+        Compression::Deflate => ZlibEncoder::new(data).read_to_end(&mut compressed).await.unwrap(),
+        Compression::Brotli => BrotliEncoder::new(data).read_to_end(&mut compressed).await.unwrap(),
+    };
+    compressed
+}
+
+#[cfg(all(target_arch = "wasm32", not(feature = "compression")))]
+async fn compress(_compression: Compression, _data: &[u8]) -> Vec<u8> {
+    unreachable!("compression can only be enabled with the compression feature")
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn fetch<Output: 'static + DeserializeOwned>(
+    url: &str,
+    config: &RequestConfig,
+    request: &mut web_sys::RequestInit,
+    headers: &web_sys::Headers,
+    method: &str,
+    idempotent: bool,
+    expected_status_code: u16
+) -> Result<Output, Error> {
+    use wasm_bindgen_futures::JsFuture;
 
     let window = web_sys::window().unwrap(); // TODO remove this unwrap
-    let response = match JsFuture::from(window.fetch_with_str_and_init(url, &request)).await {
-        Ok(response) => Response::from(response),
-        Err(e) => {
-            error!("Network error: {:?}", e);
-            return Err(Error::UnreachableServer);
+    // Fetch fails with the same `TypeError` whether or not the request reached the server,
+    // so only the requests without side effects are sent again after a network error
+    let resendable = idempotent || is_read(method, url);
+    let mut attempt = 1;
+    let mut _abort_on_drop = None;
+    let response = loop {
+        let mut result = Err(Error::UnreachableServer);
+        for (host, url) in host_urls(url, config, method) {
+            // Aborts the fetch when it is dropped because it was cancelled or timed out, or when it is retried
+            let abort_controller = web_sys::AbortController::new().map_err(|e| Error::HttpError(js_error_message(&e)))?;
+            request.signal(Some(&abort_controller.signal()));
+            _abort_on_drop = Some(AbortOnDrop(abort_controller));
+
+            result = fetch_once(&window, &url, config, request, headers, method).await;
+            let failed = match &result {
+                Ok(response) => is_transient_status(response.status()),
+                Err(e) => is_host_error(e),
+            };
+            if let (Some(host_pool), Some(host)) = (&config.host_pool, host) {
+                host_pool.set_healthy(host, !failed);
+            }
+            if !failed || !resendable {
+                break;
+            }
         }
-    };
-    let status = response.status() as u16;
-    let text = match response.text() {
-        Ok(text) => match JsFuture::from(text).await {
-            Ok(text) => text,
-            Err(e) => {
-                error!("Invalid response: {:?}", e);
-                return Err(Error::HttpError("Invalid response".to_string()));
+        let retry = match &result {
+            Ok(response) => is_transient_status(response.status()) && idempotent,
+            Err(_) => resendable,
+        };
+        match config.retry_policy {
+            Some(retry_policy) if retry && attempt < retry_policy.max_attempts => {
+                let delay = retry_policy.delay(attempt);
+                warn!("Attempt {} failed, retrying in {:?}", attempt, delay);
+                crate::progress::async_sleep(delay).await;
+                attempt += 1;
             }
-        },
-        Err(e) => {
-            error!("Invalid response: {:?}", e);
-            return Err(Error::HttpError("Invalid response".to_string()));
+            _ => break result?,
         }
     };
+    let status = response.status();
+    let text = match response.text() {
+        Ok(text) => JsFuture::from(text).await,
+        Err(e) => Err(e),
+    };
+    let text = text.map_err(|e| {
+        error!("Invalid response: {:?}", e);
+        Error::HttpError(format!("Invalid response with status {}: {}", status, js_error_message(&e)))
+    })?;
 
     if let Some(t) = text.as_string() {
         if t.is_empty() {
@@ -131,6 +867,155 @@ pub(crate) async fn request<Input: Serialize + std::fmt::Debug, Output: 'static
     }
 }
 
+/// Send a request once, calling the middlewares around it.
+#[cfg(target_arch = "wasm32")]
+async fn fetch_once(
+    window: &web_sys::Window,
+    url: &str,
+    config: &RequestConfig,
+    request: &web_sys::RequestInit,
+    headers: &web_sys::Headers,
+    method: &str,
+) -> Result<web_sys::Response, Error> {
+    use futures::future::{select, Either};
+    use wasm_bindgen::JsCast;
+    use web_sys::Response;
+    use wasm_bindgen_futures::JsFuture;
+
+    if let Some(rate_limiter) = &config.rate_limiter {
+        rate_limiter.acquire().await;
+    }
+
+    let mut middleware_request = MiddlewareRequest {
+        method,
+        url,
+        headers: Vec::new(),
+    };
+    for middleware in &config.middlewares {
+        middleware.on_request(&mut middleware_request);
+    }
+    for (name, value) in &middleware_request.headers {
+        headers.set(name, value).map_err(|_| Error::InvalidRequest)?;
+    }
+
+    let start = js_sys::Date::now();
+    let fetch = JsFuture::from(window.fetch_with_str_and_init(url, request));
+    let result = match config.timeout {
+        Some(timeout) => match select(Box::pin(fetch), Box::pin(crate::progress::async_sleep(timeout))).await {
+            Either::Left((result, _)) => result.map_err(Some),
+            Either::Right(_) => Err(None),
+        },
+        None => fetch.await.map_err(Some),
+    };
+    let result = match result {
+        Ok(response) => Ok(Response::from(response)),
+        // Fetch rejects with a `TypeError` when the request couldn't be sent because of a network error
+        Err(Some(e)) if e.is_instance_of::<js_sys::TypeError>() => {
+            error!("Network error: {}", js_error_message(&e));
+            Err(Error::UnreachableServer)
+        }
+        Err(Some(e)) => Err(Error::HttpError(js_error_message(&e))),
+        Err(None) => Err(Error::Timeout),
+    };
+    let middleware_response = MiddlewareResponse {
+        status: result.as_ref().ok().map(|response| response.status()),
+        duration: Duration::from_secs_f64((js_sys::Date::now() - start).max(0.0) / 1000.0),
+    };
+    #[cfg(feature = "tracing")]
+    record_response(&middleware_response);
+    for middleware in &config.middlewares {
+        middleware.on_response(&middleware_request, &middleware_response);
+    }
+    if let Some(metrics_observer) = &config.metrics_observer {
+        let (endpoint, index_uid) = endpoint(url);
+        let response_size = result.as_ref().ok().and_then(|response| {
+            response.headers().get("Content-Length").ok().flatten()?.parse().ok()
+        });
+        metrics_observer.observe(&RequestMetrics {
+            method,
+            endpoint,
+            index_uid,
+            status: middleware_response.status,
+            duration: middleware_response.duration,
+            // The body of the request can't be measured once in a `RequestInit`
+            request_size: None,
+            response_size,
+        });
+    }
+    result
+}
+
+/// Abort a fetch request when dropped.
+#[cfg(target_arch = "wasm32")]
+struct AbortOnDrop(web_sys::AbortController);
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// The message of a JavaScript error, or a description of the thrown value if it isn't an `Error`.
+#[cfg(target_arch = "wasm32")]
+fn js_error_message(error: &wasm_bindgen::JsValue) -> String {
+    use wasm_bindgen::JsCast;
+
+    match error.dyn_ref::<js_sys::Error>() {
+        Some(error) => String::from(error.message()),
+        None => error.as_string().unwrap_or_else(|| format!("{:?}", error)),
+    }
+}
+
+/// Percent-encode a string to be used in a query string.
+/// All characters but the unreserved ones (`A-Z`, `a-z`, `0-9`, `-`, `_`, `.` and `~`) are encoded.
+pub(crate) fn percent_encode(input: &str) -> String {
+    let mut encoded = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Encode the fields of a JSON object in a query string.
+/// Arrays of strings, numbers or booleans are sent as comma-separated lists, and other arrays and objects as JSON.
+pub(crate) fn query_string(body: &serde_json::Value) -> String {
+    use serde_json::Value;
+
+    fn is_scalar(value: &Value) -> bool {
+        matches!(value, Value::String(_) | Value::Number(_) | Value::Bool(_))
+    }
+
+    fn scalar_to_string(value: &Value) -> String {
+        match value {
+            Value::String(string) => string.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    let parameters = match body {
+        Value::Object(parameters) => parameters,
+        _ => return String::new(),
+    };
+
+    let mut pairs = Vec::new();
+    for (name, value) in parameters {
+        let value = match value {
+            Value::Null => continue,
+            Value::Array(values) if values.iter().all(is_scalar) => {
+                values.iter().map(scalar_to_string).collect::<Vec<_>>().join(",")
+            }
+            Value::Array(_) | Value::Object(_) => value.to_string(),
+            scalar => scalar_to_string(scalar),
+        };
+        pairs.push(format!("{}={}", percent_encode(name), percent_encode(&value)));
+    }
+    pairs.join("&")
+}
+
 fn parse_response<Output: DeserializeOwned>(
     status_code: u16,
     expected_status_code: u16,
@@ -149,8 +1034,129 @@ fn parse_response<Output: DeserializeOwned>(
         };
     }
     warn!("Expected response code {}, got {}", expected_status_code, status_code);
-    match from_str(&body) {
-        Ok(e) => Err(Error::from(&e)),
-        Err(e) => Err(Error::ParseError(e)),
+    match from_str::<MeilisearchError>(&body) {
+        Ok(error) => Err(Error::Meilisearch(error)),
+        Err(_) => Err(Error::UnexpectedResponse { status: status_code, body }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meilisearch_error() {
+        use crate::errors::{ErrorCode, ErrorType};
+
+        let body = r#"{"message":"Index `movies` not found.","code":"index_not_found","type":"invalid_request","link":"https://docs.meilisearch.com/errors#index_not_found"}"#;
+        let error = parse_response::<()>(404, 200, body.to_string()).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError { code: ErrorCode::IndexNotFound, ref link, .. }) if link == "https://docs.meilisearch.com/errors#index_not_found"
+        ));
+
+        // Before MeiliSearch v0.25
+        let body = r#"{"message":"Index movies not found","errorCode":"index_not_found","errorType":"invalid_request_error","errorLink":"https://docs.meilisearch.com/errors#index_not_found"}"#;
+        let error = parse_response::<()>(404, 200, body.to_string()).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError { code: ErrorCode::IndexNotFound, error_type: ErrorType::InvalidRequest, .. })
+        ));
+
+        // JSON that isn't an error of MeiliSearch
+        let error = parse_response::<()>(500, 200, r#"{"error":"proxy"}"#.to_string()).unwrap_err();
+        assert!(matches!(error, Error::UnexpectedResponse { status: 500, .. }));
+    }
+
+    #[test]
+    fn test_parse_unexpected_response() {
+        let error = parse_response::<()>(502, 200, "<html>Bad Gateway</html>".to_string()).unwrap_err();
+        assert!(matches!(error, Error::UnexpectedResponse { status: 502, ref body } if body == "<html>Bad Gateway</html>"));
+    }
+
+    #[test]
+    fn test_host_pool() {
+        let host_pool = HostPool::new("http://primary".to_string(), vec!["http://replica".to_string()]);
+        let urls = |url| host_pool.urls(url).into_iter().map(|(_, url)| url).collect::<Vec<_>>();
+        assert_eq!(urls("http://primary/indexes"), ["http://primary/indexes", "http://replica/indexes"]);
+        assert_eq!(urls("http://primary/indexes"), ["http://replica/indexes", "http://primary/indexes"]);
+
+        // The hosts that failed are tried last
+        host_pool.set_healthy(1, false);
+        assert_eq!(urls("http://primary/indexes"), ["http://primary/indexes", "http://replica/indexes"]);
+        assert_eq!(urls("http://primary/indexes"), ["http://primary/indexes", "http://replica/indexes"]);
+        host_pool.set_healthy(1, true);
+        assert_eq!(urls("http://primary/indexes"), ["http://primary/indexes", "http://replica/indexes"]);
+
+        assert!(is_read("GET", "http://primary/indexes/movies/documents"));
+        assert!(is_read("GET", "http://primary/indexes/movies/documents/1?fields=title"));
+        assert!(is_read("POST", "http://primary/indexes/movies/search"));
+        assert!(!is_read("POST", "http://primary/indexes/movies/documents"));
+        assert!(!is_read("GET", "http://primary/tasks/1"));
+        assert!(!is_read("GET", "http://primary/indexes/movies"));
+        assert!(!is_read("GET", "http://primary/indexes/movies/settings"));
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "compression"))]
+    #[test]
+    fn test_decompress_response() {
+        use async_compression::futures::bufread::{BrotliEncoder, GzipEncoder, ZlibEncoder};
+        use futures::io::AsyncReadExt;
+
+        futures::executor::block_on(async {
+            let data = &br#"{"status":"available"}"#[..];
+            let mut brotli = Vec::new();
+            BrotliEncoder::new(data).read_to_end(&mut brotli).await.unwrap();
+            let mut gzip = Vec::new();
+            GzipEncoder::new(data).read_to_end(&mut gzip).await.unwrap();
+            let mut zlib = Vec::new();
+            ZlibEncoder::new(data).read_to_end(&mut zlib).await.unwrap();
+
+            for (content_encoding, compressed) in [("br", brotli), ("gzip", gzip), ("deflate", zlib)] {
+                let response = isahc::Response::builder()
+                    .status(200)
+                    .header("Content-Encoding", content_encoding)
+                    .body(isahc::AsyncBody::from(compressed))
+                    .unwrap();
+                let body: serde_json::Value = read_response(response, 200).await.unwrap();
+                assert_eq!(body["status"], "available");
+            }
+        });
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let is_wait = |result: Result<(), Duration>, millis: f64| matches!(result, Err(wait) if (wait.as_secs_f64() * 1000.0 - millis).abs() < 1e-3);
+
+        let rate_limiter = RateLimiter::new(RateLimit::new(20.0, 2));
+        let start = rate_limiter.bucket.lock().unwrap().1;
+        // The burst is used right away, and then a request can be sent every 50ms
+        assert_eq!(rate_limiter.try_acquire(start), Ok(()));
+        assert_eq!(rate_limiter.try_acquire(start), Ok(()));
+        assert!(is_wait(rate_limiter.try_acquire(start), 50.0));
+        assert!(is_wait(rate_limiter.try_acquire(start + 20.0), 30.0));
+        assert_eq!(rate_limiter.try_acquire(start + 60.0), Ok(()));
+        assert!(is_wait(rate_limiter.try_acquire(start + 60.0), 40.0));
+        // The bucket doesn't fill beyond the burst
+        assert_eq!(rate_limiter.try_acquire(start + 10_000.0), Ok(()));
+        assert_eq!(rate_limiter.try_acquire(start + 10_000.0), Ok(()));
+        assert!(rate_limiter.try_acquire(start + 10_000.0).is_err());
+
+        // Rates that aren't positive are clamped, so that requests are throttled instead of waiting forever or not at all
+        for requests_per_second in [0.0, -1.0, f64::NAN] {
+            assert_eq!(RateLimit::new(requests_per_second, 1).requests_per_second, RateLimit::MIN_REQUESTS_PER_SECOND);
+            let rate_limiter = RateLimiter::new(RateLimit { requests_per_second, burst: 1 });
+            let start = rate_limiter.bucket.lock().unwrap().1;
+            assert_eq!(rate_limiter.try_acquire(start), Ok(()));
+            assert!(is_wait(rate_limiter.try_acquire(start), 1_000_000.0));
+        }
+    }
+
+    #[test]
+    fn test_endpoint() {
+        assert_eq!(endpoint("http://localhost:7700/indexes/movies/documents?limit=1"), ("/indexes/movies/documents", Some("movies")));
+        assert_eq!(endpoint("http://localhost:7700/indexes/movies"), ("/indexes/movies", Some("movies")));
+        assert_eq!(endpoint("http://localhost:7700/indexes"), ("/indexes", None));
+        assert_eq!(endpoint("http://localhost:7700/keys"), ("/keys", None));
     }
 }
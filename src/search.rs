@@ -1,50 +1,415 @@
-use crate::{errors::Error, indexes::Index};
+use crate::{errors::Error, indexes::Index, request::*};
 use serde::{de::DeserializeOwned, Deserialize, Serialize, Serializer};
 use std::collections::HashMap;
 
-#[derive(Deserialize, Debug, PartialEq)]
+/// The position of a match in a field of a document.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct MatchRange {
-    start: usize,
-    length: usize,
+    /// Position of the first byte of the match in the field
+    pub start: usize,
+    /// Length of the match, in bytes
+    pub length: usize,
+}
+
+/// Details of the `words` ranking rule.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WordsRankingDetails {
+    /// Position of the rule in the ranking rules of the index
+    pub order: usize,
+    /// Number of query words found in the document
+    pub matching_words: usize,
+    /// Number of words in the query
+    pub max_matching_words: usize,
+    /// Score of the rule, between `0.0` and `1.0`
+    pub score: f64,
+}
+
+/// Details of the `typo` ranking rule.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypoRankingDetails {
+    /// Position of the rule in the ranking rules of the index
+    pub order: usize,
+    /// Number of typos needed to match the document
+    pub typo_count: usize,
+    /// Maximum number of typos allowed for the query
+    pub max_typo_count: usize,
+    /// Score of the rule, between `0.0` and `1.0`
+    pub score: f64,
+}
+
+/// Details of the `proximity` ranking rule.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProximityRankingDetails {
+    /// Position of the rule in the ranking rules of the index
+    pub order: usize,
+    /// Score of the rule, between `0.0` and `1.0`
+    pub score: f64,
+}
+
+/// Details of the `attribute` ranking rule.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeRankingDetails {
+    /// Position of the rule in the ranking rules of the index
+    pub order: usize,
+    /// Score depending on the importance of the attribute in which the query words were found
+    pub attribute_ranking_order_score: Option<f64>,
+    /// Score depending on the position of the query words in the attribute
+    pub query_word_distance_score: Option<f64>,
+    /// Score of the rule, between `0.0` and `1.0`
+    pub score: f64,
+}
+
+/// Details of the `exactness` ranking rule.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExactnessRankingDetails {
+    /// Position of the rule in the ranking rules of the index
+    pub order: usize,
+    /// How exactly the document matches the query (`exactMatch`, `matchesStart` or `noExactMatch`)
+    pub match_type: Option<String>,
+    /// Number of query words matched exactly
+    pub matching_words: Option<usize>,
+    /// Number of words in the query
+    pub max_matching_words: Option<usize>,
+    /// Score of the rule, between `0.0` and `1.0`
+    pub score: f64,
+}
+
+/// The per-rule breakdown of the relevancy score of a result.
+/// Only present if [`Query::show_ranking_score_details`] was set to `true`.
+///
+/// Rules that did not take part in the ranking are `None`.
+/// Custom ranking rules (such as sorts) are kept as raw JSON in [`custom`](#structfield.custom), indexed by their name.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RankingScoreDetails {
+    /// Details of the `words` rule
+    pub words: Option<WordsRankingDetails>,
+    /// Details of the `typo` rule
+    pub typo: Option<TypoRankingDetails>,
+    /// Details of the `proximity` rule
+    pub proximity: Option<ProximityRankingDetails>,
+    /// Details of the `attribute` rule
+    pub attribute: Option<AttributeRankingDetails>,
+    /// Details of the `exactness` rule
+    pub exactness: Option<ExactnessRankingDetails>,
+    /// Details of the other rules, such as `price:asc`
+    #[serde(flatten)]
+    pub custom: HashMap<String, serde_json::Value>,
 }
 
 /// A single result.
 /// Contains the complete object, optionally the formatted object, and optionally an object that contains information about the matches.
+///
+/// The formatted object is deserialized into `F`, which defaults to the type of the complete object.
+/// When only some attributes are cropped or highlighted, a different type can be used, such as `serde_json::Map<String, serde_json::Value>`.
+/// See [`Query::execute_formatted`].
 #[derive(Deserialize, Debug)]
-pub struct SearchResult<T> {
+pub struct SearchResult<T, F = T> {
     /// The full result.
     #[serde(flatten)]
     pub result: T,
     /// The formatted result.
     #[serde(rename = "_formatted")]
-    pub formatted_result: Option<T>,
+    pub formatted_result: Option<F>,
     /// The object that contains information about the matches.
-    #[serde(rename = "_matchesInfo")]
+    ///
+    /// It is deserialized from `_matchesInfo` or, on newer versions of MeiliSearch, from `_matchesPosition`.
+    #[serde(rename = "_matchesInfo", alias = "_matchesPosition")]
     pub matches_info: Option<HashMap<String, Vec<MatchRange>>>,
+    /// The relevancy score of the result, between `0.0` and `1.0`.
+    /// Only present if [`Query::show_ranking_score`] was set to `true`.
+    #[serde(rename = "_rankingScore")]
+    pub ranking_score: Option<f64>,
+    /// The details of the relevancy score of the result.
+    /// Only present if [`Query::show_ranking_score_details`] was set to `true`.
+    #[serde(rename = "_rankingScoreDetails")]
+    pub ranking_score_details: Option<RankingScoreDetails>,
+}
+
+impl<T: Serialize, F> SearchResult<T, F> {
+    /// Highlight the matches of the result on the client side, using the [match ranges](#structfield.matches_info).
+    /// Every match is surrounded by `pre` and `post`.
+    ///
+    /// Returns the highlighted value of every string field having matches, indexed by field name.
+    /// The [matches](Query::matches) must have been requested in the query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::search::SearchResult;
+    /// # use serde::{Serialize, Deserialize};
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     title: String,
+    /// }
+    ///
+    /// # let result: SearchResult<Movie> = serde_json::from_str(r#"{"title": "The Social Network", "_matchesInfo": {"title": [{"start": 4, "length": 6}]}}"#).unwrap();
+    /// let highlighted = result.highlight_with("<b>", "</b>");
+    /// assert_eq!(highlighted["title"], "The <b>Social</b> Network");
+    /// ```
+    pub fn highlight_with(&self, pre: &str, post: &str) -> HashMap<String, String> {
+        let mut highlighted = HashMap::new();
+        let matches_info = match &self.matches_info {
+            Some(matches_info) => matches_info,
+            None => return highlighted,
+        };
+        let document = match serde_json::to_value(&self.result) {
+            Ok(document) => document,
+            Err(_) => return highlighted,
+        };
+
+        for (field, ranges) in matches_info {
+            let value = field
+                .split('.')
+                .try_fold(&document, |value, key| value.get(key));
+            if let Some(serde_json::Value::String(text)) = value {
+                highlighted.insert(field.clone(), highlight(text, ranges, pre, post));
+            }
+        }
+
+        highlighted
+    }
+}
+
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn ceil_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+fn highlight(text: &str, ranges: &[MatchRange], pre: &str, post: &str) -> String {
+    let mut spans: Vec<(usize, usize)> = ranges
+        .iter()
+        .map(|range| {
+            let start = floor_char_boundary(text, range.start);
+            (start, ceil_char_boundary(text, range.start.saturating_add(range.length)))
+        })
+        .filter(|(start, end)| start < end)
+        .collect();
+    spans.sort_unstable();
+
+    // Overlapping and adjacent ranges are merged, so that every highlighted span is surrounded only once
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end.max(*last_end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for (start, end) in merged {
+        result.push_str(&text[cursor..start]);
+        result.push_str(pre);
+        result.push_str(&text[start..end]);
+        result.push_str(post);
+        cursor = end;
+    }
+    result.push_str(&text[cursor..]);
+
+    result
 }
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 /// A struct containing search results and other information about the search.
-pub struct SearchResults<T> {
+pub struct SearchResults<T, F = T> {
     /// Results of the query
-    pub hits: Vec<SearchResult<T>>,
-    /// Number of documents skipped
-    pub offset: usize,
-    /// Number of results returned
-    pub limit: usize,
-    /// Total number of matches
-    pub nb_hits: usize,
-    /// Whether nb_hits is exhaustive
-    pub exhaustive_nb_hits: bool,
+    pub hits: Vec<SearchResult<T, F>>,
+    /// Pagination information, depending on the pagination mode of the query
+    #[serde(flatten)]
+    pub pagination: Pagination,
     /// Distribution of the given facets
     pub facets_distribution: Option<HashMap<String, HashMap<String, usize>>>,
     /// Whether facet_distribution is exhaustive
     pub exhaustive_facets_count: Option<bool>,
+    /// Minimum and maximum values of the given numeric facets
+    pub facet_stats: Option<HashMap<String, FacetStats>>,
     /// Processing time of the query
     pub processing_time_ms: usize,
     /// Query originating the response
     pub query: String,
+    /// The query these results originate from, used to fetch the other pages
+    #[serde(skip)]
+    pub(crate) origin: Option<OwnedQuery>,
+}
+
+impl<T, F> SearchResults<T, F> {
+    pub(crate) fn next_page_query(&self) -> Option<OwnedQuery> {
+        let origin = self.origin.as_ref()?;
+        match self.pagination {
+            Pagination::Exhaustive { page, total_pages, .. } if page < total_pages => {
+                Some(origin.with_parameter("page", page + 1))
+            }
+            Pagination::Estimated { offset, limit, nb_hits, .. } if limit > 0 && offset + limit < nb_hits => {
+                Some(origin.with_parameter("offset", offset + limit))
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn previous_page_query(&self) -> Option<OwnedQuery> {
+        let origin = self.origin.as_ref()?;
+        match self.pagination {
+            Pagination::Exhaustive { page, .. } if page > 1 => Some(origin.with_parameter("page", page - 1)),
+            Pagination::Estimated { offset, limit, .. } if offset > 0 => {
+                Some(origin.with_parameter("offset", offset.saturating_sub(limit)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<T: 'static + DeserializeOwned, F: 'static + DeserializeOwned> SearchResults<T, F> {
+    /// Execute the originating query again to fetch the next page of results.
+    ///
+    /// Returns `None` if this is the last page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::Client, search::*};
+    /// # futures::executor::block_on(async move {
+    /// # let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let mut page = movies.search().with_limit(10).execute::<serde_json::Value>().await.unwrap();
+    /// loop {
+    ///     for hit in &page {
+    ///         println!("{:?}", hit.result);
+    ///     }
+    ///     match page.next_page().await.unwrap() {
+    ///         Some(next_page) => page = next_page,
+    ///         None => break,
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub async fn next_page(&self) -> Result<Option<SearchResults<T, F>>, Error> {
+        match self.next_page_query() {
+            Some(query) => query.execute_formatted().await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Execute the originating query again to fetch the previous page of results.
+    ///
+    /// Returns `None` if this is the first page.
+    pub async fn previous_page(&self) -> Result<Option<SearchResults<T, F>>, Error> {
+        match self.previous_page_query() {
+            Some(query) => query.execute_formatted().await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T, F> IntoIterator for SearchResults<T, F> {
+    type Item = SearchResult<T, F>;
+    type IntoIter = std::vec::IntoIter<SearchResult<T, F>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.hits.into_iter()
+    }
+}
+
+impl<'a, T, F> IntoIterator for &'a SearchResults<T, F> {
+    type Item = &'a SearchResult<T, F>;
+    type IntoIter = std::slice::Iter<'a, SearchResult<T, F>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.hits.iter()
+    }
+}
+
+/// The minimum and maximum values of a numeric facet among the matching documents.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FacetStats {
+    /// Lowest value of the facet
+    pub min: f64,
+    /// Highest value of the facet
+    pub max: f64,
+}
+
+/// Pagination information of [`SearchResults`].
+///
+/// The variant depends on the parameters of the [`Query`]:
+/// setting [`page`](Query::page) or [`hits_per_page`](Query::hits_per_page) enables exhaustive pagination,
+/// otherwise results are paginated with [`offset`](Query::offset) and [`limit`](Query::limit) and the number of hits is estimated.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Pagination {
+    /// Finite pagination, with an exhaustive number of hits.
+    #[serde(rename_all = "camelCase")]
+    Exhaustive {
+        /// Current page number, starting at `1`
+        page: usize,
+        /// Maximum number of results in a page
+        hits_per_page: usize,
+        /// Exhaustive total number of matches
+        total_hits: usize,
+        /// Exhaustive total number of pages
+        total_pages: usize,
+    },
+    /// Offset/limit pagination, with an estimated number of hits.
+    #[serde(rename_all = "camelCase")]
+    Estimated {
+        /// Number of documents skipped
+        offset: usize,
+        /// Number of results returned
+        limit: usize,
+        /// Total number of matches
+        #[serde(alias = "estimatedTotalHits")]
+        nb_hits: usize,
+        /// Whether nb_hits is exhaustive
+        exhaustive_nb_hits: Option<bool>,
+    },
+}
+
+/// Search results whose hits are kept as raw JSON, without being parsed.
+/// See [`Index::search_raw_hits`](crate::indexes::Index::search_raw_hits).
+#[derive(Debug)]
+pub struct RawSearchResults {
+    /// The hits, exactly as they were returned by MeiliSearch
+    pub hits: Vec<Box<serde_json::value::RawValue>>,
+    /// The other fields of the response (pagination, facets, processing time...), as raw JSON
+    pub metadata: std::collections::BTreeMap<String, Box<serde_json::value::RawValue>>,
+}
+
+/// A hit that could not be deserialized.
+/// See [`Index::search_lossy`](crate::indexes::Index::search_lossy).
+#[derive(Debug)]
+pub struct HitError {
+    /// Position of the hit in the response
+    pub position: usize,
+    /// The hit, as returned by MeiliSearch
+    pub document: serde_json::Value,
+    /// The deserialization error
+    pub error: serde_json::Error,
+}
+
+/// Search results in which hits that could not be deserialized are reported instead of failing the whole search.
+/// See [`Index::search_lossy`](crate::indexes::Index::search_lossy).
+#[derive(Debug)]
+pub struct LossySearchResults<T> {
+    /// The search results, containing only the hits that were successfully deserialized
+    pub results: SearchResults<T>,
+    /// The hits that could not be deserialized
+    pub errors: Vec<HitError>,
 }
 
 fn serialize_with_wildcard<S: Serializer, T: Serialize>(
@@ -91,6 +456,173 @@ pub enum Selectors<T> {
     All,
 }
 
+/// The strategy used by MeiliSearch to match query terms within documents.
+/// See [`Query::matching_strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MatchingStrategy {
+    /// Drop the query terms from last to first until enough documents are found.
+    Last,
+    /// Only return documents containing all the query terms.
+    All,
+    /// Drop the most frequent query terms first until enough documents are found.
+    Frequency,
+}
+
+/// The `filter` parameter of a [`Query`].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::search::Filter;
+/// // genre = horror AND director = "Jordan Peele"
+/// let filter = Filter::Expression("genre = horror AND director = \"Jordan Peele\"");
+///
+/// // (genre = horror OR genre = comedy) AND director = "Jordan Peele"
+/// let filter = Filter::Array(&[&["genre = horror", "genre = comedy"], &["director = \"Jordan Peele\""]]);
+/// ```
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(untagged)]
+pub enum Filter<'a> {
+    /// A filter expression, using the [filter syntax](https://docs.meilisearch.com/reference/features/filtering.html).
+    Expression(&'a str),
+    /// An array of conditions.
+    /// The inner arrays are combined with `AND`, and the conditions inside an inner array are combined with `OR`.
+    Array(&'a [&'a [&'a str]]),
+}
+
+impl<'a> From<&'a str> for Filter<'a> {
+    fn from(expression: &'a str) -> Filter<'a> {
+        Filter::Expression(expression)
+    }
+}
+
+impl<'a> From<&'a [&'a [&'a str]]> for Filter<'a> {
+    fn from(array: &'a [&'a [&'a str]]) -> Filter<'a> {
+        Filter::Array(array)
+    }
+}
+
+/// A geographic point, used to sort results by their distance to it, and to represent the `_geo` field of documents.
+///
+/// The sort expressions it produces can be used with [`Query::with_sort`].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::Client, search::GeoPoint};
+/// # let client = Client::new("http://localhost:7700", "masterKey");
+/// # let index = client.assume_index("does not matter");
+/// let closest_first = GeoPoint { lat: 48.8566, lng: 2.3522 }.asc().unwrap();
+/// assert_eq!(closest_first, "_geoPoint(48.8566, 2.3522):asc");
+///
+/// let query = index.search()
+///     .with_sort(&[closest_first.as_str(), "price:asc"])
+///     .build();
+/// ```
+///
+/// To enable geosearch on documents, store their position in a `_geo` field, and add `_geo` to the filterable or sortable attributes of the index.
+/// Coordinates sent as strings, which MeiliSearch accepts, are parsed when deserializing.
+///
+/// ```
+/// # use meilisearch_sdk::search::GeoPoint;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize, Debug)]
+/// struct Restaurant {
+///     id: usize,
+///     name: String,
+///     #[serde(rename = "_geo")]
+///     geo: GeoPoint,
+/// }
+///
+/// let restaurant = Restaurant { id: 1, name: String::from("Nàpiz' Milano"), geo: GeoPoint::new(45.4777599, 9.1967508) };
+/// assert_eq!(
+///     serde_json::to_string(&restaurant).unwrap(),
+///     r#"{"id":1,"name":"Nàpiz' Milano","_geo":{"lat":45.4777599,"lng":9.1967508}}"#
+/// );
+///
+/// let restaurant: Restaurant = serde_json::from_str(r#"{"id":1,"name":"Nàpiz' Milano","_geo":{"lat":"45.4777599","lng":"9.1967508"}}"#).unwrap();
+/// assert_eq!(restaurant.geo, GeoPoint::new(45.4777599, 9.1967508));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    /// Latitude of the point
+    #[serde(deserialize_with = "deserialize_coordinate")]
+    pub lat: f64,
+    /// Longitude of the point
+    #[serde(deserialize_with = "deserialize_coordinate")]
+    pub lng: f64,
+}
+
+impl GeoPoint {
+    /// Create a point from its latitude and longitude.
+    pub fn new(lat: f64, lng: f64) -> GeoPoint {
+        GeoPoint { lat, lng }
+    }
+
+    /// Sort expression putting the closest documents first.
+    /// Fails if a coordinate is NaN or infinite.
+    pub fn asc(&self) -> Result<String, crate::filter::NonFiniteNumber> {
+        self.sort("asc")
+    }
+
+    /// Sort expression putting the farthest documents first.
+    /// Fails if a coordinate is NaN or infinite.
+    pub fn desc(&self) -> Result<String, crate::filter::NonFiniteNumber> {
+        self.sort("desc")
+    }
+
+    fn sort(&self, order: &str) -> Result<String, crate::filter::NonFiniteNumber> {
+        crate::filter::check_finite(&[self.lat, self.lng])?;
+        Ok(format!("_geoPoint({}, {}):{}", self.lat, self.lng, order))
+    }
+
+    /// Filter selecting the documents within `distance_m` meters of this point.
+    /// Fails if a coordinate or the distance is NaN or infinite.
+    pub fn radius(&self, distance_m: f64) -> Result<crate::filter::GeoRadius, crate::filter::NonFiniteNumber> {
+        crate::filter::GeoRadius::new(self.lat, self.lng, distance_m)
+    }
+}
+
+impl From<(f64, f64)> for GeoPoint {
+    fn from((lat, lng): (f64, f64)) -> GeoPoint {
+        GeoPoint { lat, lng }
+    }
+}
+
+/// Deserialize a coordinate sent either as a number or as a string.
+fn deserialize_coordinate<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Coordinate {
+        Number(f64),
+        String(String),
+    }
+
+    match Coordinate::deserialize(deserializer)? {
+        Coordinate::Number(coordinate) => Ok(coordinate),
+        Coordinate::String(coordinate) => coordinate.trim().parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// The HTTP method used to send a search request.
+/// See [`Query::method`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMethod {
+    /// Send the parameters in a JSON body, with a `POST` request.
+    Post,
+    /// Send the parameters in the query string, with a `GET` request.
+    /// This allows caching the responses with a CDN or a reverse proxy.
+    Get,
+}
+
+impl Default for SearchMethod {
+    fn default() -> SearchMethod {
+        SearchMethod::Post
+    }
+}
+
 type AttributeToCrop<'a> = (&'a str, Option<usize>);
 
 /// A struct representing a query.
@@ -125,7 +657,18 @@ type AttributeToCrop<'a> = (&'a str, Option<usize>);
 pub struct Query<'a> {
     #[serde(skip_serializing)]
     index: &'a Index,
+    /// The HTTP method used to send the query.
+    ///
+    /// With [`SearchMethod::Get`], array parameters are sent as comma-separated lists and
+    /// the parameters that can't be represented in a query string (such as [filter arrays](Filter::Array)) are sent as JSON.
+    ///
+    /// Default: [`SearchMethod::Post`]
+    #[serde(skip_serializing)]
+    pub method: SearchMethod,
     /// The text that will be searched for among the documents.
+    ///
+    /// If it is not set, a [placeholder search](https://docs.meilisearch.com/reference/features/search_parameters.html#placeholder-search) is performed:
+    /// all documents are returned, sorted by the ranking rules of the index and restricted by the filters of the query.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "q")]
     pub query: Option<&'a str>,
@@ -144,14 +687,55 @@ pub struct Query<'a> {
     /// Default: `20`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<usize>,
+    /// The page of results to return, starting at `1`.
+    /// Setting this parameter enables [exhaustive pagination](Pagination::Exhaustive), and `offset` and `limit` are ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+    /// The maximum number of documents in a page.
+    /// Setting this parameter enables [exhaustive pagination](Pagination::Exhaustive), and `offset` and `limit` are ignored.
+    ///
+    /// Default: `20`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hits_per_page: Option<usize>,
+    /// Restricts the search to the given attributes.
+    /// The attributes must be part of the searchable attributes of the index.
+    ///
+    /// Default: all searchable attributes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes_to_search_on: Option<&'a [&'a str]>,
+    /// Languages used in the query, as [ISO-639](https://iso639-3.sil.org/code_tables/639/data) codes.
+    /// This helps MeiliSearch pick the right tokenizer for multilingual content.
+    ///
+    /// Example: `&["jpn", "eng"]`
+    /// Default: detected automatically
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locales: Option<&'a [&'a str]>,
     /// Filters applied to documents.
     /// Read the [dedicated guide](https://docs.meilisearch.com/reference/features/filtering.html) to learn the syntax.
+    ///
+    /// This is the legacy parameter name. Newer versions of MeiliSearch expect [filter](#structfield.filter) instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filters: Option<&'a str>,
+    /// Filter applied to documents, either as a string expression or as an array of conditions.
+    /// See [`Filter`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter<'a>>,
     /// Facet names and values to filter on.
     /// Read [this page](https://docs.meilisearch.com/reference/features/search_parameters.html#facet-filters) for a complete explanation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub facet_filters: Option<&'a [&'a [&'a str]]>,
+    /// Attributes to sort the results by, in order of precedence.
+    /// Each element must be formatted as `attribute:asc` or `attribute:desc`.
+    /// The attributes must be declared as sortable in the settings of the index.
+    ///
+    /// Example: `&["price:asc", "rating:desc"]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<&'a [&'a str]>,
+    /// Attribute used to deduplicate the results: only one document is returned for each value of this attribute.
+    /// It overrides the distinct attribute of the index settings for this query only.
+    /// The attribute must be filterable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distinct: Option<&'a str>,
     /// Facets for which to retrieve the matching count.
     ///
     /// Can be set to a [wildcard value](enum.Selectors.html#variant.All) that will select all existing attributes.
@@ -179,6 +763,13 @@ pub struct Query<'a> {
     /// Default: `200`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub crop_length: Option<usize>,
+    /// Marker inserted at the boundaries of a cropped value.
+    /// Use an empty string to disable the marker.
+    /// See [attributes_to_crop](#structfield.attributes_to_crop).
+    ///
+    /// Default: `"…"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crop_marker: Option<&'a str>,
     /// Attributes whose values will contain **highlighted matching terms**.
     ///
     /// Can be set to a [wildcard value](enum.Selectors.html#variant.All) that will select all existing attributes.
@@ -190,6 +781,37 @@ pub struct Query<'a> {
     /// Default: `false`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub matches: Option<bool>,
+    /// Defines whether an object that contains information about the matches should be returned or not.
+    /// This is the name of the [matches](#structfield.matches) parameter on newer versions of MeiliSearch.
+    ///
+    /// Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_matches_position: Option<bool>,
+    /// Defines how the query terms are matched against documents.
+    ///
+    /// Default: [`MatchingStrategy::Last`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_strategy: Option<MatchingStrategy>,
+    /// Defines whether the relevancy score of each document should be returned or not.
+    /// See [`SearchResult::ranking_score`].
+    ///
+    /// Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_ranking_score: Option<bool>,
+    /// Defines whether the per-rule details of the relevancy score of each document should be returned or not.
+    /// See [`SearchResult::ranking_score_details`].
+    ///
+    /// Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_ranking_score_details: Option<bool>,
+    /// Excludes the documents whose [relevancy score](SearchResult::ranking_score) is lower than this value, between `0.0` and `1.0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking_score_threshold: Option<f64>,
+    /// Additional parameters, sent as is in the body of the request.
+    ///
+    /// This allows using search parameters that are not yet supported by this crate.
+    #[serde(flatten)]
+    pub extra: Option<HashMap<String, serde_json::Value>>,
 }
 
 #[allow(missing_docs)]
@@ -197,19 +819,38 @@ impl<'a> Query<'a> {
     pub fn new(index: &'a Index) -> Query<'a> {
         Query {
             index,
+            method: SearchMethod::Post,
             query: None,
             offset: None,
             limit: None,
+            page: None,
+            hits_per_page: None,
+            attributes_to_search_on: None,
+            locales: None,
             filters: None,
+            filter: None,
             facet_filters: None,
+            sort: None,
+            distinct: None,
             facets_distribution: None,
             attributes_to_retrieve: None,
             attributes_to_crop: None,
             crop_length: None,
+            crop_marker: None,
             attributes_to_highlight: None,
             matches: None,
+            show_matches_position: None,
+            matching_strategy: None,
+            show_ranking_score: None,
+            show_ranking_score_details: None,
+            ranking_score_threshold: None,
+            extra: None,
         }
     }
+    pub fn with_method<'b>(&'b mut self, method: SearchMethod) -> &'b mut Query<'a> {
+        self.method = method;
+        self
+    }
     pub fn with_query<'b>(&'b mut self, query: &'a str) -> &'b mut Query<'a> {
         self.query = Some(query);
         self
@@ -222,10 +863,33 @@ impl<'a> Query<'a> {
         self.limit = Some(limit);
         self
     }
+    pub fn with_page<'b>(&'b mut self, page: usize) -> &'b mut Query<'a> {
+        self.page = Some(page);
+        self
+    }
+    pub fn with_hits_per_page<'b>(&'b mut self, hits_per_page: usize) -> &'b mut Query<'a> {
+        self.hits_per_page = Some(hits_per_page);
+        self
+    }
+    pub fn with_attributes_to_search_on<'b>(
+        &'b mut self,
+        attributes_to_search_on: &'a [&'a str],
+    ) -> &'b mut Query<'a> {
+        self.attributes_to_search_on = Some(attributes_to_search_on);
+        self
+    }
+    pub fn with_locales<'b>(&'b mut self, locales: &'a [&'a str]) -> &'b mut Query<'a> {
+        self.locales = Some(locales);
+        self
+    }
     pub fn with_filters<'b>(&'b mut self, filters: &'a str) -> &'b mut Query<'a> {
         self.filters = Some(filters);
         self
     }
+    pub fn with_filter<'b>(&'b mut self, filter: Filter<'a>) -> &'b mut Query<'a> {
+        self.filter = Some(filter);
+        self
+    }
     pub fn with_facet_filters<'b>(
         &'b mut self,
         facet_filters: &'a [&'a [&'a str]],
@@ -233,6 +897,14 @@ impl<'a> Query<'a> {
         self.facet_filters = Some(facet_filters);
         self
     }
+    pub fn with_sort<'b>(&'b mut self, sort: &'a [&'a str]) -> &'b mut Query<'a> {
+        self.sort = Some(sort);
+        self
+    }
+    pub fn with_distinct<'b>(&'b mut self, distinct: &'a str) -> &'b mut Query<'a> {
+        self.distinct = Some(distinct);
+        self
+    }
     pub fn with_facets_distribution<'b>(
         &'b mut self,
         facets_distribution: Selectors<&'a [&'a str]>,
@@ -265,28 +937,199 @@ impl<'a> Query<'a> {
         self.crop_length = Some(crop_length);
         self
     }
+    pub fn with_crop_marker<'b>(&'b mut self, crop_marker: &'a str) -> &'b mut Query<'a> {
+        self.crop_marker = Some(crop_marker);
+        self
+    }
     pub fn with_matches<'b>(&'b mut self, matches: bool) -> &'b mut Query<'a> {
         self.matches = Some(matches);
         self
     }
+    pub fn with_show_matches_position<'b>(&'b mut self, show_matches_position: bool) -> &'b mut Query<'a> {
+        self.show_matches_position = Some(show_matches_position);
+        self
+    }
+    pub fn with_matching_strategy<'b>(
+        &'b mut self,
+        matching_strategy: MatchingStrategy,
+    ) -> &'b mut Query<'a> {
+        self.matching_strategy = Some(matching_strategy);
+        self
+    }
+    pub fn with_show_ranking_score<'b>(&'b mut self, show_ranking_score: bool) -> &'b mut Query<'a> {
+        self.show_ranking_score = Some(show_ranking_score);
+        self
+    }
+    pub fn with_show_ranking_score_details<'b>(
+        &'b mut self,
+        show_ranking_score_details: bool,
+    ) -> &'b mut Query<'a> {
+        self.show_ranking_score_details = Some(show_ranking_score_details);
+        self
+    }
+    pub fn with_ranking_score_threshold<'b>(
+        &'b mut self,
+        ranking_score_threshold: f64,
+    ) -> &'b mut Query<'a> {
+        self.ranking_score_threshold = Some(ranking_score_threshold);
+        self
+    }
+    pub fn with_extra_parameter<'b>(
+        &'b mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> &'b mut Query<'a> {
+        self.extra
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
     pub fn build(&mut self) -> Query<'a> {
         self.clone()
     }
 
+    /// The index the query will be executed on.
+    pub fn index(&self) -> &'a Index {
+        self.index
+    }
+
+    /// Build an [`OwnedQuery`], which does not borrow anything.
+    /// Fails if the parameters of the query can't be serialized to JSON.
+    pub fn build_owned(&self) -> Result<OwnedQuery, Error> {
+        Ok(OwnedQuery {
+            index: self.index.clone(),
+            method: self.method,
+            body: serde_json::to_value(self).map_err(Error::ParseError)?,
+        })
+    }
+
     /// Execute the query and fetch the results.
     pub async fn execute<T: 'static + DeserializeOwned>(
         &'a self,
     ) -> Result<SearchResults<T>, Error> {
         self.index.execute_query::<T>(&self).await
     }
+
+    /// Execute the query and fetch the results, deserializing the [formatted results](SearchResult::formatted_result) into `F`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::Client, search::*};
+    /// # use serde::{Serialize, Deserialize};
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     title: String,
+    ///     description: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// # let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.get_or_create("movies").await.unwrap();
+    ///
+    /// // Only the title is highlighted, so the formatted results can't be deserialized into a `Movie`
+    /// let results = movies.search()
+    ///     .with_query("space")
+    ///     .with_attributes_to_retrieve(Selectors::Some(&["title"]))
+    ///     .with_attributes_to_highlight(Selectors::Some(&["title"]))
+    ///     .execute_formatted::<serde_json::Value, serde_json::Map<String, serde_json::Value>>()
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// ```
+    pub async fn execute_formatted<T: 'static + DeserializeOwned, F: 'static + DeserializeOwned>(
+        &'a self,
+    ) -> Result<SearchResults<T, F>, Error> {
+        self.index.execute_query_formatted::<T, F>(&self).await
+    }
+}
+
+/// An owned version of a [`Query`], without lifetimes.
+///
+/// Unlike a [`Query`], it can be returned from helper functions, stored in structs or moved across async boundaries.
+/// It is obtained with [`Query::build_owned`].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{errors::Error, indexes::Index, search::OwnedQuery};
+/// fn space_query(index: &Index, limit: usize) -> Result<OwnedQuery, Error> {
+///     let kind = String::from("title");
+///     let filters = format!("kind = {}", kind);
+///     let query = index.search()
+///         .with_query("space")
+///         .with_filters(&filters)
+///         .with_limit(limit)
+///         .build_owned();
+///     query
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OwnedQuery {
+    index: Index,
+    method: SearchMethod,
+    body: serde_json::Value,
+}
+
+impl OwnedQuery {
+    /// The index the query will be executed on.
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Execute the query and fetch the results.
+    pub async fn execute<T: 'static + DeserializeOwned>(&self) -> Result<SearchResults<T>, Error> {
+        self.execute_formatted::<T, T>().await
+    }
+
+    /// Execute the query and fetch the results, deserializing the [formatted results](SearchResult::formatted_result) into `F`.
+    pub async fn execute_formatted<T: 'static + DeserializeOwned, F: 'static + DeserializeOwned>(
+        &self,
+    ) -> Result<SearchResults<T, F>, Error> {
+        let mut results = self.send::<SearchResults<T, F>>().await?;
+        results.origin = Some(self.clone());
+        Ok(results)
+    }
+
+    /// Send the query to MeiliSearch, with the [method](Query::method) it was built with.
+    pub(crate) async fn send<Output: 'static + DeserializeOwned>(&self) -> Result<Output, Error> {
+        let url = format!("{}/indexes/{}/search", self.index.host, self.index.uid);
+        match self.method {
+            SearchMethod::Post => {
+                request::<&serde_json::Value, Output>(&url, &self.index.config, Method::Post(&self.body), 200).await
+            }
+            SearchMethod::Get => {
+                request::<(), Output>(
+                    &format!("{}?{}", url, query_string(&self.body)),
+                    &self.index.config,
+                    Method::Get,
+                    200,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Copy of the query with a parameter of the request body overwritten.
+    pub(crate) fn with_parameter(&self, name: &str, value: impl Into<serde_json::Value>) -> OwnedQuery {
+        let mut query = self.clone();
+        if let serde_json::Value::Object(body) = &mut query.body {
+            body.insert(name.to_string(), value.into());
+        }
+        query
+    }
+}
+
+impl Serialize for OwnedQuery {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.body.serialize(serializer)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{client::*, document, search::*};
     use serde::{Deserialize, Serialize};
-    use std::thread::sleep;
-    use std::time::Duration;
     use futures_await_test::async_test;
 
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -304,12 +1147,9 @@ mod tests {
         }
     }
 
-    #[allow(unused_must_use)]
     async fn setup_test_index<'a>(client: &'a Client, name: &'a str) -> Index {
-        // try to delete
-        client.delete_index(name).await;
-
-        let index = client.create_index(name, None).await.unwrap();
+        client.delete_index_if_exists(name).await.unwrap();
+        let index = client.get_or_create(name).await.unwrap();
         index.add_documents(&[
             Document { id: 0, kind: "text".into(), value: "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum.".to_string() },
             Document { id: 1, kind: "text".into(), value: "dolor sit amet, consectetur adipiscing elit".to_string() },
@@ -321,9 +1161,8 @@ mod tests {
             Document { id: 7, kind: "title".into(), value: "Harry Potter and the Order of the Phoenix".to_string() },
             Document { id: 8, kind: "title".into(), value: "Harry Potter and the Half-Blood Prince".to_string() },
             Document { id: 9, kind: "title".into(), value: "Harry Potter and the Deathly Hallows".to_string() },
-        ], None).await.unwrap();
-        index.set_attributes_for_faceting(["kind"]).await.unwrap();
-        sleep(Duration::from_secs(1));
+        ], None).await.unwrap().wait().await.unwrap();
+        index.set_attributes_for_faceting(["kind"]).await.unwrap().wait().await.unwrap();
         index
     }
 
@@ -339,6 +1178,120 @@ mod tests {
         client.delete_index("test_query_string").await.unwrap();
     }
 
+    #[async_test]
+    async fn test_query_placeholder() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_query_placeholder").await;
+
+        let query = Query::new(&index);
+        assert_eq!(serde_json::to_value(&query).unwrap(), serde_json::json!({}));
+
+        let results: SearchResults<Document> = index
+            .search()
+            .with_facet_filters(&[&["kind:title"]])
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(results.hits.len(), 8);
+
+        client.delete_index("test_query_placeholder").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_owned_query() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_owned_query").await;
+
+        let owned = {
+            let query = String::from("dolor");
+            let owned = index.search().with_query(&query).with_limit(1).build_owned().unwrap();
+            owned
+        };
+        assert_eq!(serde_json::to_value(&owned).unwrap(), serde_json::json!({ "q": "dolor", "limit": 1 }));
+
+        let results: SearchResults<Document> = owned.execute().await.unwrap();
+        assert_eq!(results.hits.len(), 1);
+
+        client.delete_index("test_owned_query").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_search_lossy() {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        enum Kind {
+            Title,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Title {
+            id: usize,
+            kind: Kind,
+        }
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_search_lossy").await;
+
+        let query = Query::new(&index);
+        let lossy = index.search_lossy::<Title>(&query).await.unwrap();
+        assert_eq!(lossy.results.hits.len(), 8);
+        assert!(lossy
+            .results
+            .hits
+            .iter()
+            .all(|hit| hit.result.id >= 2 && matches!(hit.result.kind, Kind::Title)));
+        assert_eq!(lossy.errors.len(), 2);
+        assert!(lossy.errors.iter().all(|error| error.document["kind"] == "text"));
+
+        client.delete_index("test_search_lossy").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_search_raw() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_search_raw").await;
+
+        let query = Query::new(&index).with_query("dolor").with_attributes_to_retrieve(Selectors::Some(&["id"])).build();
+        let response = index.search_raw(&query).await.unwrap();
+        let mut ids: Vec<u64> = response["hits"].as_array().unwrap().iter().map(|hit| hit["id"].as_u64().unwrap()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![0, 1]);
+        assert_eq!(response["query"], "dolor");
+
+        client.delete_index("test_search_raw").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_search_raw_hits() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_search_raw_hits").await;
+
+        let query = Query::new(&index).with_query("Social Network").with_limit(1).build();
+        let results = index.search_raw_hits(&query).await.unwrap();
+        assert_eq!(results.hits.len(), 1);
+        // The hits are kept as they were received, and can still be parsed later
+        let hit: Document = serde_json::from_str(results.hits[0].get()).unwrap();
+        assert_eq!(hit.id, 2);
+        assert_eq!(results.metadata["limit"].get(), "1");
+        assert!(!results.metadata.contains_key("hits"));
+
+        client.delete_index("test_search_raw_hits").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_search_all() {
+        use futures::TryStreamExt;
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_search_all").await;
+
+        let query = index.search().with_limit(3).build();
+        let hits: Vec<SearchResult<Document>> = index.search_all(&query).try_collect().await.unwrap();
+        assert_eq!(hits.len(), 10);
+
+        client.delete_index("test_search_all").await.unwrap();
+    }
+
     #[async_test]
     async fn test_query_limit() {
         let client = Client::new("http://localhost:7700", "masterKey");
@@ -363,6 +1316,104 @@ mod tests {
         client.delete_index("test_query_offset").await.unwrap();
     }
 
+    #[test]
+    fn test_search_results_pagination() {
+        let results: SearchResults<Document> = serde_json::from_value(serde_json::json!({
+            "hits": [],
+            "offset": 0,
+            "limit": 20,
+            "nbHits": 10,
+            "exhaustiveNbHits": false,
+            "processingTimeMs": 1,
+            "query": "",
+        }))
+        .unwrap();
+        assert_eq!(results.pagination, Pagination::Estimated {
+            offset: 0,
+            limit: 20,
+            nb_hits: 10,
+            exhaustive_nb_hits: Some(false),
+        });
+
+        let results: SearchResults<Document> = serde_json::from_value(serde_json::json!({
+            "hits": [],
+            "page": 2,
+            "hitsPerPage": 5,
+            "totalHits": 10,
+            "totalPages": 2,
+            "processingTimeMs": 1,
+            "query": "",
+        }))
+        .unwrap();
+        assert_eq!(results.pagination, Pagination::Exhaustive {
+            page: 2,
+            hits_per_page: 5,
+            total_hits: 10,
+            total_pages: 2,
+        });
+    }
+
+    #[test]
+    fn test_search_results_pages() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_search_results_pages");
+
+        let mut results: SearchResults<Document> = serde_json::from_value(serde_json::json!({
+            "hits": [],
+            "offset": 0,
+            "limit": 4,
+            "nbHits": 10,
+            "processingTimeMs": 1,
+            "query": "",
+        }))
+        .unwrap();
+        assert!(results.next_page_query().is_none());
+
+        results.origin = Some(index.search().with_query("dolor").with_limit(4).build_owned().unwrap());
+        let next = results.next_page_query().unwrap();
+        assert_eq!(
+            serde_json::to_value(&next).unwrap(),
+            serde_json::json!({ "q": "dolor", "limit": 4, "offset": 4 })
+        );
+        assert!(results.previous_page_query().is_none());
+
+        results.pagination = Pagination::Estimated {
+            offset: 8,
+            limit: 4,
+            nb_hits: 10,
+            exhaustive_nb_hits: None,
+        };
+        assert!(results.next_page_query().is_none());
+        assert_eq!(
+            serde_json::to_value(&results.previous_page_query().unwrap()).unwrap()["offset"],
+            4
+        );
+
+        results.pagination = Pagination::Exhaustive {
+            page: 2,
+            hits_per_page: 4,
+            total_hits: 10,
+            total_pages: 3,
+        };
+        assert_eq!(serde_json::to_value(&results.next_page_query().unwrap()).unwrap()["page"], 3);
+        assert_eq!(serde_json::to_value(&results.previous_page_query().unwrap()).unwrap()["page"], 1);
+    }
+
+    #[test]
+    fn test_search_results_facet_stats() {
+        let results: SearchResults<Document> = serde_json::from_value(serde_json::json!({
+            "hits": [],
+            "offset": 0,
+            "limit": 20,
+            "estimatedTotalHits": 10,
+            "facetStats": { "id": { "min": 0, "max": 9 } },
+            "processingTimeMs": 1,
+            "query": "",
+        }))
+        .unwrap();
+        assert_eq!(results.facet_stats.unwrap()["id"], FacetStats { min: 0.0, max: 9.0 });
+    }
+
     #[async_test]
     async fn test_query_filters() {
         let client = Client::new("http://localhost:7700", "masterKey");
@@ -387,6 +1438,30 @@ mod tests {
         client.delete_index("test_query_filters").await.unwrap();
     }
 
+    #[async_test]
+    async fn test_query_filter() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_query_filter").await;
+
+        let results: SearchResults<Document> = index
+            .search()
+            .with_filter(Filter::Expression("kind = text"))
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(results.hits.len(), 2);
+
+        let results: SearchResults<Document> = index
+            .search()
+            .with_filter(Filter::Array(&[&["kind = text", "kind = title"], &["id < 3"]]))
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(results.hits.len(), 3);
+
+        client.delete_index("test_query_filter").await.unwrap();
+    }
+
     #[async_test]
     async fn test_query_facet_filters() {
         let client = Client::new("http://localhost:7700", "masterKey");
@@ -413,6 +1488,205 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_query_sort() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_query_sort");
+
+        let mut query = Query::new(&index);
+        query.with_sort(&["kind:asc", "id:desc"]);
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(value, serde_json::json!({ "sort": ["kind:asc", "id:desc"] }));
+    }
+
+    #[test]
+    fn test_geo_point_sort() {
+        let point = GeoPoint { lat: -0.0000001, lng: 180.0 };
+        assert_eq!(point.asc().unwrap(), "_geoPoint(-0.0000001, 180):asc");
+        assert_eq!(point.desc().unwrap(), "_geoPoint(-0.0000001, 180):desc");
+        assert_eq!(GeoPoint::new(f64::NAN, 2.3522).asc().unwrap_err().to_string(), "filter numbers must be finite, got NaN");
+        assert!(GeoPoint::new(48.8566, f64::INFINITY).desc().is_err());
+    }
+
+    #[test]
+    fn test_geo_point_serde() {
+        let point = GeoPoint::new(45.4777599, 9.1967508);
+        assert_eq!(serde_json::to_value(&point).unwrap(), serde_json::json!({ "lat": 45.4777599, "lng": 9.1967508 }));
+        assert_eq!(serde_json::from_value::<GeoPoint>(serde_json::json!({ "lat": "45.4777599", "lng": 9.1967508 })).unwrap(), point);
+        assert!(serde_json::from_value::<GeoPoint>(serde_json::json!({ "lat": "north", "lng": 9.1967508 })).is_err());
+        assert_eq!(point.radius(2000.0).unwrap().to_string(), "_geoRadius(45.4777599, 9.1967508, 2000)");
+    }
+
+    #[test]
+    fn test_query_matching_strategy() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_query_matching_strategy");
+
+        let mut query = Query::new(&index);
+        query.with_query("harry potter").with_matching_strategy(MatchingStrategy::All);
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(value, serde_json::json!({ "q": "harry potter", "matchingStrategy": "all" }));
+    }
+
+    #[test]
+    fn test_search_result_matches_position() {
+        let result: SearchResult<Document> = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "value": "dolor sit amet",
+            "kind": "text",
+            "_matchesPosition": { "value": [{ "start": 0, "length": 5 }] },
+        }))
+        .unwrap();
+        assert_eq!(
+            result.matches_info.unwrap()["value"],
+            vec![MatchRange { start: 0, length: 5 }]
+        );
+    }
+
+    #[test]
+    fn test_highlight_with() {
+        let result: SearchResult<Document> = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "value": "dolor sit amet, dolor",
+            "kind": "text",
+            "_matchesInfo": {
+                "value": [{ "start": 16, "length": 5 }, { "start": 0, "length": 5 }, { "start": 2, "length": 5 }],
+                "kind": [{ "start": 0, "length": 100 }],
+                "id": [{ "start": 0, "length": 1 }],
+            },
+        }))
+        .unwrap();
+        let highlighted = result.highlight_with("<em>", "</em>");
+        assert_eq!(highlighted.len(), 2);
+        assert_eq!(highlighted["value"], "<em>dolor s</em>it amet, <em>dolor</em>");
+        assert_eq!(highlighted["kind"], "<em>text</em>");
+
+        assert_eq!(
+            highlight("été à Paris", &[MatchRange { start: 1, length: 2 }], "[", "]"),
+            "[ét]é à Paris"
+        );
+        // Adjacent and contained ranges are merged too
+        assert_eq!(
+            highlight(
+                "New York City",
+                &[MatchRange { start: 4, length: 4 }, MatchRange { start: 0, length: 4 }, MatchRange { start: 5, length: 2 }],
+                "[",
+                "]"
+            ),
+            "[New York] City"
+        );
+    }
+
+    #[test]
+    fn test_search_result_partial_formatted() {
+        let result: SearchResult<Document, serde_json::Map<String, serde_json::Value>> =
+            serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "value": "dolor sit amet",
+                "kind": "text",
+                "_formatted": { "value": "<em>dolor</em> sit amet" },
+            }))
+            .unwrap();
+        assert_eq!(result.formatted_result.unwrap()["value"], "<em>dolor</em> sit amet");
+
+        let result: Result<SearchResult<Document>, _> = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "value": "dolor sit amet",
+            "kind": "text",
+            "_formatted": { "value": "<em>dolor</em> sit amet" },
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_search_result_ranking_score() {
+        let result: SearchResult<Document> = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "value": "dolor sit amet",
+            "kind": "text",
+            "_rankingScore": 0.875,
+        }))
+        .unwrap();
+        assert_eq!(result.ranking_score, Some(0.875));
+        assert_eq!(result.result.id, 1);
+    }
+
+    #[test]
+    fn test_search_result_ranking_score_details() {
+        let result: SearchResult<Document> = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "value": "dolor sit amet",
+            "kind": "text",
+            "_rankingScoreDetails": {
+                "words": { "order": 0, "matchingWords": 1, "maxMatchingWords": 1, "score": 1.0 },
+                "typo": { "order": 1, "typoCount": 0, "maxTypoCount": 1, "score": 1.0 },
+                "kind:asc": { "order": 2, "value": "text" },
+            },
+        }))
+        .unwrap();
+        let details = result.ranking_score_details.unwrap();
+        assert_eq!(details.words.unwrap().matching_words, 1);
+        assert_eq!(details.typo.unwrap().typo_count, 0);
+        assert!(details.proximity.is_none());
+        assert_eq!(details.custom["kind:asc"]["value"], "text");
+    }
+
+    #[async_test]
+    async fn test_query_attributes_to_search_on() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_query_attributes_to_search_on").await;
+
+        let results: SearchResults<Document> = index
+            .search()
+            .with_query("title")
+            .with_attributes_to_search_on(&["value"])
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(results.hits.len(), 0);
+
+        client
+            .delete_index("test_query_attributes_to_search_on")
+            .await
+            .unwrap();
+    }
+
+    #[async_test]
+    async fn test_query_locales() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = setup_test_index(&client, "test_query_locales").await;
+
+        let mut query = Query::new(&index);
+        query.with_query("dolor").with_locales(&["eng", "lat"]);
+        assert_eq!(serde_json::to_value(&query).unwrap(), serde_json::json!({ "q": "dolor", "locales": ["eng", "lat"] }));
+        let results: SearchResults<Document> = query.execute().await.unwrap();
+        assert_eq!(results.hits.len(), 2);
+
+        client.delete_index("test_query_locales").await.unwrap();
+    }
+
+    #[test]
+    fn test_query_distinct() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_query_distinct");
+
+        let mut query = Query::new(&index);
+        query.with_distinct("kind");
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(value, serde_json::json!({ "distinct": "kind" }));
+    }
+
+    #[test]
+    fn test_query_ranking_score_threshold() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_query_ranking_score_threshold");
+
+        let mut query = Query::new(&index);
+        query.with_show_ranking_score(true).with_ranking_score_threshold(0.5);
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(value, serde_json::json!({ "showRankingScore": true, "rankingScoreThreshold": 0.5 }));
+    }
+
     #[async_test]
     async fn test_query_facet_distribution() {
         let client = Client::new("http://localhost:7700", "masterKey");
@@ -554,6 +1828,55 @@ mod tests {
         client.delete_index("test_query_crop_lenght").await.unwrap();
     }
 
+    #[test]
+    fn test_query_extra_parameters() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_query_extra_parameters");
+
+        let mut query = Query::new(&index);
+        query
+            .with_limit(3)
+            .with_extra_parameter("hybrid", serde_json::json!({ "semanticRatio": 0.5 }))
+            .with_extra_parameter("retrieveVectors", true);
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(value, serde_json::json!({
+            "limit": 3,
+            "hybrid": { "semanticRatio": 0.5 },
+            "retrieveVectors": true,
+        }));
+    }
+
+    #[test]
+    fn test_get_query_string() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_get_query_string");
+
+        let mut query = Query::new(&index);
+        query
+            .with_method(SearchMethod::Get)
+            .with_query("harry & sally")
+            .with_limit(5)
+            .with_attributes_to_retrieve(Selectors::Some(&["id", "value"]))
+            .with_filter(Filter::Array(&[&["kind = title"]]));
+        let owned = query.build_owned().unwrap();
+        assert_eq!(owned.method, SearchMethod::Get);
+        assert_eq!(
+            query_string(&owned.body),
+            "attributesToRetrieve=id%2Cvalue&filter=%5B%5B%22kind%20%3D%20title%22%5D%5D&limit=5&q=harry%20%26%20sally"
+        );
+    }
+
+    #[test]
+    fn test_query_crop_marker() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.assume_index("test_query_crop_marker");
+
+        let mut query = Query::new(&index);
+        query.with_attributes_to_crop(Selectors::All).with_crop_marker("");
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(value, serde_json::json!({ "attributesToCrop": ["*"], "cropMarker": "" }));
+    }
+
     #[async_test]
     async fn test_query_attributes_to_highlight() {
         let client = Client::new("http://localhost:7700", "masterKey");
@@ -1,14 +1,16 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, io::{Read, Write}};
 use crate::{
     indexes::Index,
     errors::Error,
     request::{request, Method},
-    progress::{Progress, ProgressJson}
+    progress::TaskHandle,
+    tasks::TaskInfo,
 };
 
 /// Struct reprensenting a set of settings.
 /// You can build this struct using the builder syntax.
+/// Fields left to `None` are not serialized, so [`Index::set_settings`](../indexes/struct.Index.html#method.set_settings) only updates the provided settings.
 ///
 /// # Example
 ///
@@ -31,7 +33,7 @@ use crate::{
 ///     ..Settings::new()
 /// };
 /// ```
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
     /// List of associated words treated similarly
@@ -55,6 +57,366 @@ pub struct Settings {
     /// Fields displayed in the returned documents
     #[serde(skip_serializing_if = "Option::is_none")]
     pub displayed_attributes: Option<Vec<String>>,
+    /// Attributes that can be used in [filters](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) and facets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filterable_attributes: Option<Vec<String>>,
+    /// Attributes that can be used to [sort](https://docs.meilisearch.com/reference/features/sorting.html) search results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sortable_attributes: Option<Vec<String>>,
+    /// How [typos](https://docs.meilisearch.com/learn/configuration/typo_tolerance.html) are handled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typo_tolerance: Option<TypoToleranceSettings>,
+    /// How [facets](https://docs.meilisearch.com/learn/advanced/faceted_search.html) are returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faceting: Option<FacetingSettings>,
+    /// How far search results can be paginated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<PaginationSettings>,
+    /// Strings that are always considered as a single word, like `J. R. R. Tolkien`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dictionary: Option<Vec<String>>,
+    /// Strings that separate words, in addition to the default separators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub separator_tokens: Option<Vec<String>>,
+    /// Strings that are not considered as separators anymore, like `#` or `-`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_separator_tokens: Option<Vec<String>>,
+    /// How precisely the distance between query words is computed by the `proximity` ranking rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_precision: Option<ProximityPrecision>,
+    /// Maximum duration of a search, in milliseconds (default: 1500)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_cutoff_ms: Option<u64>,
+    /// [Embedders](https://docs.meilisearch.com/learn/ai_powered_search/getting_started_with_ai_search.html) generating the vectors used by vector search, by name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedders: Option<HashMap<String, Embedder>>,
+}
+
+/// The configuration of an embedder, turning documents and queries into vectors.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::{Embedder, OpenAiEmbedder};
+/// let embedder = Embedder::OpenAi(OpenAiEmbedder {
+///     api_key: Some("<your-openai-api-key>".to_string()),
+///     model: Some("text-embedding-3-small".to_string()),
+///     document_template: Some("A movie titled {{doc.title}}".to_string()),
+///     ..OpenAiEmbedder::default()
+/// });
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "source", rename_all = "camelCase")]
+pub enum Embedder {
+    /// Vectors computed by the OpenAI API
+    OpenAi(OpenAiEmbedder),
+    /// Vectors computed locally by MeiliSearch with a HuggingFace model
+    HuggingFace(HuggingFaceEmbedder),
+    /// Vectors computed by an Ollama server
+    Ollama(OllamaEmbedder),
+    /// Vectors computed by any REST API
+    Rest(RestEmbedder),
+    /// Vectors provided along with the documents and the queries
+    UserProvided(UserProvidedEmbedder),
+}
+
+/// Configuration of an [`Embedder::OpenAi`] embedder.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAiEmbedder {
+    /// The OpenAI API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// The OpenAI model (default: `text-embedding-3-small`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Number of dimensions of the vectors, for models supporting several
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+    /// URL of an OpenAI-compatible API, to use instead of the OpenAI one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// [Liquid template](https://shopify.github.io/liquid/) turning a document into the text to embed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    /// Distribution of the semantic scores, to make them comparable with the keyword scores
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<EmbedderDistribution>,
+    /// Whether to store the vectors as bits, trading relevancy for size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+/// Configuration of an [`Embedder::HuggingFace`] embedder.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HuggingFaceEmbedder {
+    /// The HuggingFace model (default: `BAAI/bge-base-en-v1.5`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The revision (commit) of the model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// [Liquid template](https://shopify.github.io/liquid/) turning a document into the text to embed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    /// Distribution of the semantic scores, to make them comparable with the keyword scores
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<EmbedderDistribution>,
+    /// Whether to store the vectors as bits, trading relevancy for size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+/// Configuration of an [`Embedder::Ollama`] embedder.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OllamaEmbedder {
+    /// URL of the Ollama embeddings endpoint (default: `http://localhost:11434/api/embeddings`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// API key sent to the Ollama server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// The Ollama model, such as `nomic-embed-text`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Number of dimensions of the vectors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+    /// [Liquid template](https://shopify.github.io/liquid/) turning a document into the text to embed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    /// Distribution of the semantic scores, to make them comparable with the keyword scores
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<EmbedderDistribution>,
+    /// Whether to store the vectors as bits, trading relevancy for size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+/// Configuration of an [`Embedder::Rest`] embedder.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RestEmbedder {
+    /// URL of the embedding API
+    pub url: String,
+    /// API key sent as a bearer token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// Number of dimensions of the vectors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+    /// [Liquid template](https://shopify.github.io/liquid/) turning a document into the text to embed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    /// Template of the request body, where `{{text}}` is replaced by the text to embed
+    pub request: serde_json::Value,
+    /// Template of the response body, where `{{embedding}}` marks the location of the vector
+    pub response: serde_json::Value,
+    /// Additional headers sent with each request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+    /// Distribution of the semantic scores, to make them comparable with the keyword scores
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<EmbedderDistribution>,
+    /// Whether to store the vectors as bits, trading relevancy for size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+/// Configuration of an [`Embedder::UserProvided`] embedder.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProvidedEmbedder {
+    /// Number of dimensions of the vectors
+    pub dimensions: usize,
+    /// Distribution of the semantic scores, to make them comparable with the keyword scores
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<EmbedderDistribution>,
+    /// Whether to store the vectors as bits, trading relevancy for size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+/// The distribution of the semantic scores of an embedder, used to correct them.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct EmbedderDistribution {
+    /// The mean of the scores
+    pub mean: f64,
+    /// The standard deviation of the scores
+    pub sigma: f64,
+}
+
+/// How precisely the distance between query words is computed by the `proximity` ranking rule.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProximityPrecision {
+    /// Compute the exact distance between words (default)
+    ByWord,
+    /// Only check whether words are in the same attribute, which makes indexing faster
+    ByAttribute,
+}
+
+/// [Typo tolerance](https://docs.meilisearch.com/learn/configuration/typo_tolerance.html) settings of an index.
+/// Fields set to `None` are left unchanged when updating the settings.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::TypoToleranceSettings;
+/// // Never tolerate typos in SKUs
+/// let typo_tolerance = TypoToleranceSettings::new()
+///     .with_min_word_size_for_typos(4, 8)
+///     .with_disable_on_attributes(["sku"]);
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypoToleranceSettings {
+    /// Whether typos are tolerated at all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Minimum length of a word to tolerate typos in it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_word_size_for_typos: Option<MinWordSizeForTypos>,
+    /// Words on which typos are never tolerated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_on_words: Option<Vec<String>>,
+    /// Attributes in which typos are never tolerated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_on_attributes: Option<Vec<String>>,
+}
+
+/// Minimum length of a word to tolerate typos in it.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MinWordSizeForTypos {
+    /// Minimum length of a word to tolerate one typo (default: 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_typo: Option<u8>,
+    /// Minimum length of a word to tolerate two typos (default: 9)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub two_typos: Option<u8>,
+}
+
+/// Faceting settings of an index.
+/// Fields set to `None` are left unchanged when updating the settings.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::{FacetingSettings, FacetSortValue};
+/// let faceting = FacetingSettings::new()
+///     .with_max_values_per_facet(500)
+///     .with_sort_facet_values_by("*", FacetSortValue::Alpha)
+///     .with_sort_facet_values_by("genres", FacetSortValue::Count);
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetingSettings {
+    /// Maximum number of values returned for each facet (default: 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_values_per_facet: Option<usize>,
+    /// How the values of each facet are sorted, by facet name. `*` applies to all facets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_facet_values_by: Option<HashMap<String, FacetSortValue>>,
+}
+
+/// How the values of a facet are sorted.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FacetSortValue {
+    /// In alphanumerical order
+    Alpha,
+    /// By decreasing number of matching documents
+    Count,
+}
+
+/// Pagination settings of an index.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::PaginationSettings;
+/// let pagination = PaginationSettings {
+///     max_total_hits: Some(10_000),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaginationSettings {
+    /// Maximum number of hits a search can return, whatever the offset and the limit (default: 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_hits: Option<usize>,
+}
+
+#[allow(missing_docs)]
+impl FacetingSettings {
+    /// Create undefined faceting settings
+    pub fn new() -> FacetingSettings {
+        FacetingSettings::default()
+    }
+    pub fn with_max_values_per_facet(self, max_values_per_facet: usize) -> FacetingSettings {
+        FacetingSettings {
+            max_values_per_facet: Some(max_values_per_facet),
+            ..self
+        }
+    }
+    pub fn with_sort_facet_values_by(self, facet: impl Into<String>, sort: FacetSortValue) -> FacetingSettings {
+        let mut sort_facet_values_by = self.sort_facet_values_by.unwrap_or_default();
+        sort_facet_values_by.insert(facet.into(), sort);
+        FacetingSettings {
+            sort_facet_values_by: Some(sort_facet_values_by),
+            ..self
+        }
+    }
+}
+
+#[allow(missing_docs)]
+impl TypoToleranceSettings {
+    /// Create undefined typo tolerance settings
+    pub fn new() -> TypoToleranceSettings {
+        TypoToleranceSettings::default()
+    }
+    pub fn with_enabled(self, enabled: bool) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            enabled: Some(enabled),
+            ..self
+        }
+    }
+    pub fn with_min_word_size_for_typos(self, one_typo: u8, two_typos: u8) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            min_word_size_for_typos: Some(MinWordSizeForTypos {
+                one_typo: Some(one_typo),
+                two_typos: Some(two_typos),
+            }),
+            ..self
+        }
+    }
+    pub fn with_disable_on_words(self, disable_on_words: impl IntoVecString) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            disable_on_words: Some(disable_on_words.convert()),
+            ..self
+        }
+    }
+    pub fn with_disable_on_attributes(self, disable_on_attributes: impl IntoVecString) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            disable_on_attributes: Some(disable_on_attributes.convert()),
+            ..self
+        }
+    }
 }
 
 pub trait IntoVecString: Sized {
@@ -152,6 +514,84 @@ impl<const N: usize> IntoVecString for [&str; N] {
     }
 }
 
+/// A [ranking rule](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules).
+///
+/// The built-in rules are unit variants, and custom rules sort documents by the value of an attribute.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::RankingRule;
+/// let mut ranking_rules = RankingRule::DEFAULT.to_vec();
+/// ranking_rules.push(RankingRule::Desc("release_date".to_string()));
+///
+/// assert_eq!(ranking_rules.last().unwrap().to_string(), "release_date:desc");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RankingRule {
+    /// Sort by decreasing number of matched query terms.
+    Words,
+    /// Sort by increasing number of typos.
+    Typo,
+    /// Sort by increasing distance between matched query terms.
+    Proximity,
+    /// Sort by the importance of the attributes in which the query terms were found.
+    Attribute,
+    /// Sort by the `sort` parameter of the query.
+    Sort,
+    /// Sort by the similarity of the matched words with the query words.
+    Exactness,
+    /// Sort by increasing value of an attribute.
+    Asc(String),
+    /// Sort by decreasing value of an attribute.
+    Desc(String),
+}
+
+impl RankingRule {
+    /// The ranking rules of a new index, in their default order.
+    pub const DEFAULT: [RankingRule; 6] = [
+        RankingRule::Words,
+        RankingRule::Typo,
+        RankingRule::Proximity,
+        RankingRule::Attribute,
+        RankingRule::Sort,
+        RankingRule::Exactness,
+    ];
+}
+
+impl std::fmt::Display for RankingRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankingRule::Words => f.write_str("words"),
+            RankingRule::Typo => f.write_str("typo"),
+            RankingRule::Proximity => f.write_str("proximity"),
+            RankingRule::Attribute => f.write_str("attribute"),
+            RankingRule::Sort => f.write_str("sort"),
+            RankingRule::Exactness => f.write_str("exactness"),
+            RankingRule::Asc(attribute) => write!(f, "{}:asc", attribute),
+            RankingRule::Desc(attribute) => write!(f, "{}:desc", attribute),
+        }
+    }
+}
+
+impl IntoVecString for &[RankingRule] {
+    fn convert(self) -> Vec<String> {
+        self.iter().map(|rule| rule.to_string()).collect()
+    }
+}
+
+impl IntoVecString for Vec<RankingRule> {
+    fn convert(self) -> Vec<String> {
+        self.iter().map(|rule| rule.to_string()).collect()
+    }
+}
+
+impl<const N: usize> IntoVecString for [RankingRule; N] {
+    fn convert(self) -> Vec<String> {
+        self.iter().map(|rule| rule.to_string()).collect()
+    }
+}
+
 #[allow(missing_docs)]
 impl Settings {
     /// Create undefined settings
@@ -164,6 +604,17 @@ impl Settings {
             distinct_attribute: None,
             searchable_attributes: None,
             displayed_attributes: None,
+            filterable_attributes: None,
+            sortable_attributes: None,
+            typo_tolerance: None,
+            faceting: None,
+            pagination: None,
+            dictionary: None,
+            separator_tokens: None,
+            non_separator_tokens: None,
+            proximity_precision: None,
+            search_cutoff_ms: None,
+            embedders: None,
         }
     }
     pub fn with_synonyms<T: Into<String>, U: IntoVecString>(self, synonyms: HashMap<T, U>) -> Settings {
@@ -216,6 +667,218 @@ impl Settings {
             ..self
         }
     }
+    pub fn with_filterable_attributes<T: IntoVecString>(self, filterable_attributes: T) -> Settings {
+        Settings {
+            filterable_attributes: Some(filterable_attributes.convert()),
+            ..self
+        }
+    }
+    pub fn with_sortable_attributes<T: IntoVecString>(self, sortable_attributes: T) -> Settings {
+        Settings {
+            sortable_attributes: Some(sortable_attributes.convert()),
+            ..self
+        }
+    }
+    pub fn with_typo_tolerance(self, typo_tolerance: TypoToleranceSettings) -> Settings {
+        Settings {
+            typo_tolerance: Some(typo_tolerance),
+            ..self
+        }
+    }
+    pub fn with_faceting(self, faceting: FacetingSettings) -> Settings {
+        Settings {
+            faceting: Some(faceting),
+            ..self
+        }
+    }
+    pub fn with_pagination(self, pagination: PaginationSettings) -> Settings {
+        Settings {
+            pagination: Some(pagination),
+            ..self
+        }
+    }
+    pub fn with_dictionary(self, dictionary: impl IntoVecString) -> Settings {
+        Settings {
+            dictionary: Some(dictionary.convert()),
+            ..self
+        }
+    }
+    pub fn with_separator_tokens(self, separator_tokens: impl IntoVecString) -> Settings {
+        Settings {
+            separator_tokens: Some(separator_tokens.convert()),
+            ..self
+        }
+    }
+    pub fn with_non_separator_tokens(self, non_separator_tokens: impl IntoVecString) -> Settings {
+        Settings {
+            non_separator_tokens: Some(non_separator_tokens.convert()),
+            ..self
+        }
+    }
+    pub fn with_proximity_precision(self, proximity_precision: ProximityPrecision) -> Settings {
+        Settings {
+            proximity_precision: Some(proximity_precision),
+            ..self
+        }
+    }
+    pub fn with_search_cutoff_ms(self, search_cutoff_ms: u64) -> Settings {
+        Settings {
+            search_cutoff_ms: Some(search_cutoff_ms),
+            ..self
+        }
+    }
+    pub fn with_embedders<T: Into<String>>(self, embedders: HashMap<T, Embedder>) -> Settings {
+        Settings {
+            embedders: Some(embedders.into_iter().map(|(name, embedder)| (name.into(), embedder)).collect()),
+            ..self
+        }
+    }
+
+    /// Read settings from JSON, in the format used by the MeiliSearch API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let json = r#"{ "stopWords": ["a", "the"], "distinctAttribute": "url" }"#;
+    /// let settings = Settings::from_reader(json.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(settings, Settings::new().with_stop_words(["a", "the"]).with_distinct_attribute("url"));
+    /// ```
+    pub fn from_reader(reader: impl Read) -> Result<Settings, Error> {
+        serde_json::from_reader(reader).map_err(json_error)
+    }
+
+    /// Write the settings as pretty-printed JSON, in the format used by the MeiliSearch API.
+    /// Settings that are `None` are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let settings = Settings::new().with_stop_words(["a", "the"]);
+    ///
+    /// let mut json = Vec::new();
+    /// settings.to_writer(&mut json).unwrap();
+    /// assert_eq!(Settings::from_reader(json.as_slice()).unwrap(), settings);
+    /// ```
+    pub fn to_writer(&self, writer: impl Write) -> Result<(), Error> {
+        serde_json::to_writer_pretty(writer, self).map_err(json_error)
+    }
+
+    /// Compute which settings would change if `other` was applied over `self`.
+    ///
+    /// Settings that are `None` in `other` are left unchanged, as [`Index::set_settings`](../indexes/struct.Index.html#method.set_settings) would do.
+    /// Lists whose order doesn't matter (such as stop words or filterable attributes) are compared as sets,
+    /// while the order of ranking rules and searchable attributes is significant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let current = Settings::new()
+    ///     .with_stop_words(["the", "a"])
+    ///     .with_distinct_attribute("url");
+    /// let wanted = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_distinct_attribute("id");
+    ///
+    /// let diff = current.diff(&wanted);
+    /// assert_eq!(diff.changed, vec!["distinctAttribute"]);
+    /// assert_eq!(diff.changes, Settings::new().with_distinct_attribute("id"));
+    /// ```
+    pub fn diff(&self, other: &Settings) -> SettingsDiff {
+        fn same<T: PartialEq>(old: &T, new: &T) -> bool {
+            old == new
+        }
+        fn same_set(old: &[String], new: &[String]) -> bool {
+            let mut old = old.to_vec();
+            let mut new = new.to_vec();
+            old.sort();
+            new.sort();
+            old == new
+        }
+        fn same_synonyms(old: &HashMap<String, Vec<String>>, new: &HashMap<String, Vec<String>>) -> bool {
+            old.len() == new.len()
+                && new.iter().all(|(word, synonyms)| old.get(word).map_or(false, |old| same_set(old, synonyms)))
+        }
+        // The object settings are updated with PATCH, so only the fields set in the new value are compared
+        fn same_if_set<T>(old: &Option<T>, new: &Option<T>, same: impl Fn(&T, &T) -> bool) -> bool {
+            new.as_ref().map_or(true, |new| old.as_ref().map_or(false, |old| same(old, new)))
+        }
+        fn same_typo_tolerance(old: &TypoToleranceSettings, new: &TypoToleranceSettings) -> bool {
+            same_if_set(&old.enabled, &new.enabled, same)
+                && same_if_set(&old.min_word_size_for_typos, &new.min_word_size_for_typos, |old, new| {
+                    same_if_set(&old.one_typo, &new.one_typo, same) && same_if_set(&old.two_typos, &new.two_typos, same)
+                })
+                && same_if_set(&old.disable_on_words, &new.disable_on_words, |old, new| same_set(old, new))
+                && same_if_set(&old.disable_on_attributes, &new.disable_on_attributes, |old, new| same_set(old, new))
+        }
+        fn same_faceting(old: &FacetingSettings, new: &FacetingSettings) -> bool {
+            same_if_set(&old.max_values_per_facet, &new.max_values_per_facet, same)
+                && same_if_set(&old.sort_facet_values_by, &new.sort_facet_values_by, |old, new| {
+                    new.iter().all(|(facet, sort)| old.get(facet) == Some(sort))
+                })
+        }
+        fn same_pagination(old: &PaginationSettings, new: &PaginationSettings) -> bool {
+            same_if_set(&old.max_total_hits, &new.max_total_hits, same)
+        }
+
+        let mut diff = SettingsDiff::default();
+        macro_rules! compare {
+            ($field:ident, $name:literal, $same:expr) => {
+                if let Some(new) = &other.$field {
+                    if !self.$field.as_ref().map_or(false, |old| $same(old, new)) {
+                        diff.changes.$field = Some(new.clone());
+                        diff.changed.push($name);
+                    }
+                }
+            };
+        }
+        compare!(synonyms, "synonyms", same_synonyms);
+        compare!(stop_words, "stopWords", same_set);
+        compare!(ranking_rules, "rankingRules", same);
+        compare!(attributes_for_faceting, "attributesForFaceting", same_set);
+        compare!(distinct_attribute, "distinctAttribute", same);
+        compare!(searchable_attributes, "searchableAttributes", same);
+        compare!(displayed_attributes, "displayedAttributes", same_set);
+        compare!(filterable_attributes, "filterableAttributes", same_set);
+        compare!(sortable_attributes, "sortableAttributes", same_set);
+        compare!(typo_tolerance, "typoTolerance", same_typo_tolerance);
+        compare!(faceting, "faceting", same_faceting);
+        compare!(pagination, "pagination", same_pagination);
+        compare!(dictionary, "dictionary", same_set);
+        compare!(separator_tokens, "separatorTokens", same_set);
+        compare!(non_separator_tokens, "nonSeparatorTokens", same_set);
+        compare!(proximity_precision, "proximityPrecision", same);
+        compare!(search_cutoff_ms, "searchCutoffMs", same);
+        compare!(embedders, "embedders", same);
+        diff
+    }
+}
+
+fn json_error(error: serde_json::Error) -> Error {
+    if error.is_io() {
+        Error::IoError(error.into())
+    } else {
+        Error::ParseError(error)
+    }
+}
+
+/// The settings that differ between two [`Settings`], computed by [`Settings::diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SettingsDiff {
+    /// The new values of the changed settings. The other fields are `None`, so these settings can be applied as is with [`Index::set_settings`](../indexes/struct.Index.html#method.set_settings).
+    pub changes: Settings,
+    /// Names of the changed settings, as used by the MeiliSearch API (e.g. `stopWords`).
+    pub changed: Vec<&'static str>,
+}
+
+impl SettingsDiff {
+    /// Whether no setting changed.
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
 }
 
 impl Index {
@@ -232,7 +895,7 @@ impl Index {
     pub async fn get_settings(&self) -> Result<Settings, Error> {
         Ok(request::<(), Settings>(
             &format!("{}/indexes/{}/settings", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
@@ -251,7 +914,7 @@ impl Index {
     pub async fn get_synonyms(&self) -> Result<HashMap<String, Vec<String>>, Error> {
         Ok(request::<(), HashMap<String, Vec<String>>>(
             &format!("{}/indexes/{}/settings/synonyms", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
@@ -270,7 +933,7 @@ impl Index {
     pub async fn get_stop_words(&self) -> Result<Vec<String>, Error> {
         Ok(request::<(), Vec<String>>(
             &format!("{}/indexes/{}/settings/stop-words", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
@@ -289,7 +952,7 @@ impl Index {
     pub async fn get_ranking_rules(&self) -> Result<Vec<String>, Error> {
         Ok(request::<(), Vec<String>>(
             &format!("{}/indexes/{}/settings/ranking-rules", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
@@ -308,7 +971,7 @@ impl Index {
     pub async fn get_attributes_for_faceting(&self) -> Result<Vec<String>, Error> {
         Ok(request::<(), Vec<String>>(
             &format!("{}/indexes/{}/settings/attributes-for-faceting", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
@@ -327,13 +990,14 @@ impl Index {
     pub async fn get_distinct_attribute(&self) -> Result<Option<String>, Error> {
         Ok(request::<(), Option<String>>(
             &format!("{}/indexes/{}/settings/distinct-attribute", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
     }
 
     /// Get [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the Index.
+    /// They are sorted by order of importance.
     ///
     /// ```
     /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
@@ -346,7 +1010,7 @@ impl Index {
     pub async fn get_searchable_attributes(&self) -> Result<Vec<String>, Error> {
         Ok(request::<(), Vec<String>>(
             &format!("{}/indexes/{}/settings/searchable-attributes", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
@@ -365,40 +1029,271 @@ impl Index {
     pub async fn get_displayed_attributes(&self) -> Result<Vec<String>, Error> {
         Ok(request::<(), Vec<String>>(
             &format!("{}/indexes/{}/settings/displayed-attributes", self.host, self.uid),
-            &self.api_key,
+            &self.config,
             Method::Get,
             200,
         ).await?)
     }
 
-    /// Update [settings](../settings/struct.Settings.html) of the index.
-    /// Updates in the settings are partial. This means that any parameters corresponding to a None value will be left unchanged.
-    ///
-    /// # Example
+    /// Get [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the Index.
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
-    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
-    ///
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let filterable_attributes = movie_index.get_filterable_attributes().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_filterable_attributes(&self) -> Result<Vec<String>, Error> {
+        Ok(request::<(), Vec<String>>(
+            &format!("{}/indexes/{}/settings/filterable-attributes", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let sortable_attributes = movie_index.get_sortable_attributes().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_sortable_attributes(&self) -> Result<Vec<String>, Error> {
+        Ok(request::<(), Vec<String>>(
+            &format!("{}/indexes/{}/settings/sortable-attributes", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get the [typo tolerance settings](https://docs.meilisearch.com/learn/configuration/typo_tolerance.html) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let typo_tolerance = movie_index.get_typo_tolerance().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_typo_tolerance(&self) -> Result<TypoToleranceSettings, Error> {
+        Ok(request::<(), TypoToleranceSettings>(
+            &format!("{}/indexes/{}/settings/typo-tolerance", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get the [faceting settings](https://docs.meilisearch.com/reference/api/settings.html#faceting) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let faceting = movie_index.get_faceting().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_faceting(&self) -> Result<FacetingSettings, Error> {
+        Ok(request::<(), FacetingSettings>(
+            &format!("{}/indexes/{}/settings/faceting", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get the [pagination settings](https://docs.meilisearch.com/reference/api/settings.html#pagination) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let pagination = movie_index.get_pagination().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_pagination(&self) -> Result<PaginationSettings, Error> {
+        Ok(request::<(), PaginationSettings>(
+            &format!("{}/indexes/{}/settings/pagination", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get [dictionary](https://docs.meilisearch.com/reference/api/settings.html#dictionary) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let dictionary = movie_index.get_dictionary().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_dictionary(&self) -> Result<Vec<String>, Error> {
+        Ok(request::<(), Vec<String>>(
+            &format!("{}/indexes/{}/settings/dictionary", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get [separator tokens](https://docs.meilisearch.com/reference/api/settings.html#separator-tokens) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let separator_tokens = movie_index.get_separator_tokens().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_separator_tokens(&self) -> Result<Vec<String>, Error> {
+        Ok(request::<(), Vec<String>>(
+            &format!("{}/indexes/{}/settings/separator-tokens", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get [non-separator tokens](https://docs.meilisearch.com/reference/api/settings.html#non-separator-tokens) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let non_separator_tokens = movie_index.get_non_separator_tokens().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_non_separator_tokens(&self) -> Result<Vec<String>, Error> {
+        Ok(request::<(), Vec<String>>(
+            &format!("{}/indexes/{}/settings/non-separator-tokens", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get the [proximity precision](https://docs.meilisearch.com/reference/api/settings.html#proximity-precision) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let proximity_precision = movie_index.get_proximity_precision().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_proximity_precision(&self) -> Result<ProximityPrecision, Error> {
+        Ok(request::<(), ProximityPrecision>(
+            &format!("{}/indexes/{}/settings/proximity-precision", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get the [search cutoff](https://docs.meilisearch.com/reference/api/settings.html#search-cutoff) of the Index.
+    /// `None` means the default cutoff of 1500ms is used.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let search_cutoff_ms = movie_index.get_search_cutoff_ms().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_search_cutoff_ms(&self) -> Result<Option<u64>, Error> {
+        Ok(request::<(), Option<u64>>(
+            &format!("{}/indexes/{}/settings/search-cutoff-ms", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Get the [embedders](https://docs.meilisearch.com/reference/api/settings.html#embedders) of the Index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    /// let embedders = movie_index.get_embedders().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_embedders(&self) -> Result<HashMap<String, Embedder>, Error> {
+        Ok(request::<(), HashMap<String, Embedder>>(
+            &format!("{}/indexes/{}/settings/embedders", self.host, self.uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await?)
+    }
+
+    /// Update [settings](../settings/struct.Settings.html) of the index.
+    /// Updates in the settings are partial. This means that any parameters corresponding to a None value will be left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
     /// let stop_words = vec![String::from("a"), String::from("the"), String::from("of")];
     /// let settings = Settings::new()
     ///     .with_stop_words(stop_words.clone());
     ///
     /// let progress = movie_index.set_settings(&settings).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_settings(&self, settings: &Settings) -> Result<Progress, Error> {
-        Ok(request::<&Settings, ProgressJson>(
+    pub async fn set_settings(&self, settings: &Settings) -> Result<TaskHandle, Error> {
+        Ok(request::<&Settings, TaskInfo>(
             &format!("{}/indexes/{}/settings", self.host, self.uid),
-            &self.api_key,
-            Method::Post(settings),
+            &self.config,
+            Method::Patch(settings),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Update the settings of the index with the settings stored in a JSON file, as written by [`Settings::to_writer`](../settings/struct.Settings.html#method.to_writer).
+    /// This allows keeping the configuration of indexes in version control.
+    /// As with [`Index::set_settings`], settings missing from the file are left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.apply_settings_file("config/movies.json").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn apply_settings_file(&self, path: impl AsRef<std::path::Path>) -> Result<TaskHandle, Error> {
+        let file = std::fs::File::open(path).map_err(Error::IoError)?;
+        let settings = Settings::from_reader(std::io::BufReader::new(file))?;
+        self.set_settings(&settings).await
     }
 
     /// Update [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the index.
@@ -417,18 +1312,17 @@ impl Index {
     /// synonyms.insert(String::from("wow"), vec![String::from("world of warcraft")]);
     ///
     /// let progress = movie_index.set_synonyms(&synonyms).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_synonyms(&self, synonyms: &HashMap<String, Vec<String>>) -> Result<Progress, Error> {
-        Ok(request::<&HashMap<String, Vec<String>>, ProgressJson>(
+    pub async fn set_synonyms(&self, synonyms: &HashMap<String, Vec<String>>) -> Result<TaskHandle, Error> {
+        Ok(request::<&HashMap<String, Vec<String>>, TaskInfo>(
             &format!("{}/indexes/{}/settings/synonyms", self.host, self.uid),
-            &self.api_key,
-            Method::Post(synonyms),
+            &self.config,
+            Method::Put(synonyms),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Update [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the index.
@@ -443,18 +1337,17 @@ impl Index {
     ///
     /// let stop_words = ["the", "of", "to"];
     /// let progress = movie_index.set_stop_words(&stop_words).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_stop_words(&self, stop_words: impl IntoVecString) -> Result<Progress, Error> {
-        Ok(request::<Vec<String>, ProgressJson>(
+    pub async fn set_stop_words(&self, stop_words: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
             &format!("{}/indexes/{}/settings/stop-words", self.host, self.uid),
-            &self.api_key,
-            Method::Post(stop_words.convert()),
+            &self.config,
+            Method::Put(stop_words.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Update [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the index.
@@ -462,34 +1355,40 @@ impl Index {
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::{Settings, RankingRule}};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
     /// let ranking_rules = [
-    ///     "typo",
     ///     "words",
+    ///     "typo",
     ///     "proximity",
     ///     "attribute",
-    ///     "wordsPosition",
+    ///     "sort",
     ///     "exactness",
-    ///     "asc(release_date)",
-    ///     "desc(rank)",
+    ///     "release_date:asc",
+    ///     "rank:desc",
     /// ];
     /// let progress = movie_index.set_ranking_rules(ranking_rules).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    ///
+    /// // OR, using the typed ranking rules
+    ///
+    /// let mut ranking_rules = RankingRule::DEFAULT.to_vec();
+    /// ranking_rules.push(RankingRule::Desc("release_date".to_string()));
+    /// let progress = movie_index.set_ranking_rules(ranking_rules).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_ranking_rules(&self, ranking_rules: impl IntoVecString) -> Result<Progress, Error> {
-        Ok(request::<Vec<String>, ProgressJson>(
+    pub async fn set_ranking_rules(&self, ranking_rules: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
             &format!("{}/indexes/{}/settings/ranking-rules", self.host, self.uid),
-            &self.api_key,
-            Method::Post(ranking_rules.convert()),
+            &self.config,
+            Method::Put(ranking_rules.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Update [attributes for faceting](https://docs.meilisearch.com/reference/features/faceted_search.html) of the index.
@@ -504,18 +1403,17 @@ impl Index {
     ///
     /// let attributes_for_faceting = ["genre", "director"];
     /// let progress = movie_index.set_attributes_for_faceting(&attributes_for_faceting).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_attributes_for_faceting(&self, attributes_for_faceting: impl IntoVecString) -> Result<Progress, Error> {
-        Ok(request::<Vec<String>, ProgressJson>(
+    pub async fn set_attributes_for_faceting(&self, attributes_for_faceting: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
             &format!("{}/indexes/{}/settings/attributes-for-faceting", self.host, self.uid),
-            &self.api_key,
-            Method::Post(attributes_for_faceting.convert()),
+            &self.config,
+            Method::Put(attributes_for_faceting.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Update the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the index.
@@ -529,21 +1427,21 @@ impl Index {
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
     /// let progress = movie_index.set_distinct_attribute("movie_id").await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_distinct_attribute(&self, distinct_attribute: impl Into<String>) -> Result<Progress, Error> {
-        Ok(request::<String, ProgressJson>(
+    pub async fn set_distinct_attribute(&self, distinct_attribute: impl Into<String>) -> Result<TaskHandle, Error> {
+        Ok(request::<String, TaskInfo>(
             &format!("{}/indexes/{}/settings/distinct-attribute", self.host, self.uid),
-            &self.api_key,
-            Method::Post(distinct_attribute.into()),
+            &self.config,
+            Method::Put(distinct_attribute.into()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Update [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the index.
+    /// The order of the attributes matters: matches in the first attributes are considered more relevant by the `attribute` ranking rule.
     ///
     /// # Example
     ///
@@ -554,18 +1452,17 @@ impl Index {
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
     /// let progress = movie_index.set_searchable_attributes(["title", "description", "uid"]).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_searchable_attributes(&self, searchable_attributes: impl IntoVecString) -> Result<Progress, Error> {
-        Ok(request::<Vec<String>, ProgressJson>(
+    pub async fn set_searchable_attributes(&self, searchable_attributes: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
             &format!("{}/indexes/{}/settings/searchable-attributes", self.host, self.uid),
-            &self.api_key,
-            Method::Post(searchable_attributes.convert()),
+            &self.config,
+            Method::Put(searchable_attributes.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
     /// Update [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the index.
@@ -579,22 +1476,20 @@ impl Index {
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
     /// let progress = movie_index.set_displayed_attributes(["title", "description", "release_date", "rank", "poster"]).await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn set_displayed_attributes(&self, displayed_attributes: impl IntoVecString) -> Result<Progress, Error> {
-        Ok(request::<Vec<String>, ProgressJson>(
+    pub async fn set_displayed_attributes(&self, displayed_attributes: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
             &format!("{}/indexes/{}/settings/displayed-attributes", self.host, self.uid),
-            &self.api_key,
-            Method::Post(displayed_attributes.convert()),
+            &self.config,
+            Method::Put(displayed_attributes.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset [settings](../settings/struct.Settings.html) of the index.
-    /// All settings will be reset to their [default value](https://docs.meilisearch.com/reference/api/settings.html#reset-settings).
+    /// Update [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the index.
     ///
     /// # Example
     ///
@@ -604,22 +1499,21 @@ impl Index {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_settings().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let progress = movie_index.set_filterable_attributes(["genres", "director"]).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_settings(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_filterable_attributes(&self, filterable_attributes: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
+            &format!("{}/indexes/{}/settings/filterable-attributes", self.host, self.uid),
+            &self.config,
+            Method::Put(filterable_attributes.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the index.
+    /// Update [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the index.
     ///
     /// # Example
     ///
@@ -629,98 +1523,102 @@ impl Index {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_synonyms().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let progress = movie_index.set_sortable_attributes(["price", "release_date"]).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_synonyms(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings/synonyms", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_sortable_attributes(&self, sortable_attributes: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
+            &format!("{}/indexes/{}/settings/sortable-attributes", self.host, self.uid),
+            &self.config,
+            Method::Put(sortable_attributes.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the index.
+    /// Update the [typo tolerance settings](https://docs.meilisearch.com/learn/configuration/typo_tolerance.html) of the index.
+    /// Only the fields that are `Some` are updated.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::TypoToleranceSettings};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_stop_words().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let typo_tolerance = TypoToleranceSettings::new()
+    ///     .with_disable_on_attributes(["sku"]);
+    /// let progress = movie_index.set_typo_tolerance(&typo_tolerance).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_stop_words(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings/stop-words", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_typo_tolerance(&self, typo_tolerance: &TypoToleranceSettings) -> Result<TaskHandle, Error> {
+        Ok(request::<&TypoToleranceSettings, TaskInfo>(
+            &format!("{}/indexes/{}/settings/typo-tolerance", self.host, self.uid),
+            &self.config,
+            Method::Patch(typo_tolerance),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the index to default value.
-    /// Default value: ["typo", "words", "proximity", "attribute", "wordsPosition", "exactness"].
+    /// Update the [faceting settings](https://docs.meilisearch.com/reference/api/settings.html#faceting) of the index.
+    /// Only the fields that are `Some` are updated.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::FacetingSettings};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_ranking_rules().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let faceting = FacetingSettings::new()
+    ///     .with_max_values_per_facet(500);
+    /// let progress = movie_index.set_faceting(&faceting).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_ranking_rules(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings/ranking-rules", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_faceting(&self, faceting: &FacetingSettings) -> Result<TaskHandle, Error> {
+        Ok(request::<&FacetingSettings, TaskInfo>(
+            &format!("{}/indexes/{}/settings/faceting", self.host, self.uid),
+            &self.config,
+            Method::Patch(faceting),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset [attributes for faceting](https://docs.meilisearch.com/reference/features/faceted_search.html) of the index.
+    /// Update the [pagination settings](https://docs.meilisearch.com/reference/api/settings.html#pagination) of the index.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::PaginationSettings};
     /// # futures::executor::block_on(async move {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_attributes_for_faceting().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let pagination = PaginationSettings {
+    ///     max_total_hits: Some(10_000),
+    /// };
+    /// let progress = movie_index.set_pagination(&pagination).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_attributes_for_faceting(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings/attributes-for-faceting", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_pagination(&self, pagination: &PaginationSettings) -> Result<TaskHandle, Error> {
+        Ok(request::<&PaginationSettings, TaskInfo>(
+            &format!("{}/indexes/{}/settings/pagination", self.host, self.uid),
+            &self.config,
+            Method::Patch(pagination),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the index.
+    /// Update [dictionary](https://docs.meilisearch.com/reference/api/settings.html#dictionary) of the index.
     ///
     /// # Example
     ///
@@ -730,22 +1628,21 @@ impl Index {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_distinct_attribute().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let progress = movie_index.set_dictionary(["J. R. R.", "W. E. B."]).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_distinct_attribute(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings/distinct-attribute", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_dictionary(&self, dictionary: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
+            &format!("{}/indexes/{}/settings/dictionary", self.host, self.uid),
+            &self.config,
+            Method::Put(dictionary.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the index (enable all attributes).
+    /// Update [separator tokens](https://docs.meilisearch.com/reference/api/settings.html#separator-tokens) of the index.
     ///
     /// # Example
     ///
@@ -755,22 +1652,21 @@ impl Index {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_searchable_attributes().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let progress = movie_index.set_separator_tokens(["|", "&hellip;"]).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_searchable_attributes(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings/searchable-attributes", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_separator_tokens(&self, separator_tokens: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
+            &format!("{}/indexes/{}/settings/separator-tokens", self.host, self.uid),
+            &self.config,
+            Method::Put(separator_tokens.convert()),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
     }
 
-    /// Reset [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the index (enable all attributes).
+    /// Update [non-separator tokens](https://docs.meilisearch.com/reference/api/settings.html#non-separator-tokens) of the index.
     ///
     /// # Example
     ///
@@ -780,18 +1676,1028 @@ impl Index {
     /// let client = Client::new("http://localhost:7700", "masterKey");
     /// let mut movie_index = client.get_or_create("movies").await.unwrap();
     ///
-    /// let progress = movie_index.reset_displayed_attributes().await.unwrap();
-    /// # std::thread::sleep(std::time::Duration::from_secs(2));
-    /// # progress.get_status().await.unwrap();
+    /// let progress = movie_index.set_non_separator_tokens(["#", "-"]).await.unwrap();
+    /// # progress.wait().await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_displayed_attributes(&self) -> Result<Progress, Error> {
-        Ok(request::<(), ProgressJson>(
-            &format!("{}/indexes/{}/settings/displayed-attributes", self.host, self.uid),
-            &self.api_key,
-            Method::Delete,
+    pub async fn set_non_separator_tokens(&self, non_separator_tokens: impl IntoVecString) -> Result<TaskHandle, Error> {
+        Ok(request::<Vec<String>, TaskInfo>(
+            &format!("{}/indexes/{}/settings/non-separator-tokens", self.host, self.uid),
+            &self.config,
+            Method::Put(non_separator_tokens.convert()),
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Update the [proximity precision](https://docs.meilisearch.com/reference/api/settings.html#proximity-precision) of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::ProximityPrecision};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.set_proximity_precision(ProximityPrecision::ByAttribute).await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_proximity_precision(&self, proximity_precision: ProximityPrecision) -> Result<TaskHandle, Error> {
+        Ok(request::<ProximityPrecision, TaskInfo>(
+            &format!("{}/indexes/{}/settings/proximity-precision", self.host, self.uid),
+            &self.config,
+            Method::Put(proximity_precision),
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Update the [search cutoff](https://docs.meilisearch.com/reference/api/settings.html#search-cutoff) of the index.
+    /// Searches taking longer than this many milliseconds return the results found so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.set_search_cutoff_ms(150).await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_search_cutoff_ms(&self, search_cutoff_ms: u64) -> Result<TaskHandle, Error> {
+        Ok(request::<u64, TaskInfo>(
+            &format!("{}/indexes/{}/settings/search-cutoff-ms", self.host, self.uid),
+            &self.config,
+            Method::Put(search_cutoff_ms),
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Update the [embedders](https://docs.meilisearch.com/reference/api/settings.html#embedders) of the index.
+    /// Embedders missing from `embedders` are left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::{Embedder, UserProvidedEmbedder}};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let mut embedders = std::collections::HashMap::new();
+    /// embedders.insert("default".to_string(), Embedder::UserProvided(UserProvidedEmbedder {
+    ///     dimensions: 3,
+    ///     ..UserProvidedEmbedder::default()
+    /// }));
+    /// let progress = movie_index.set_embedders(&embedders).await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_embedders(&self, embedders: &HashMap<String, Embedder>) -> Result<TaskHandle, Error> {
+        Ok(request::<&HashMap<String, Embedder>, TaskInfo>(
+            &format!("{}/indexes/{}/settings/embedders", self.host, self.uid),
+            &self.config,
+            Method::Patch(embedders),
             202,
         ).await?
-        .into_progress(self))
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [settings](../settings/struct.Settings.html) of the index.
+    /// All settings will be reset to their [default value](https://docs.meilisearch.com/reference/api/settings.html#reset-settings).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_settings().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_settings(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_synonyms().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_synonyms(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/synonyms", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_stop_words().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_stop_words(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/stop-words", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the index to default value.
+    /// Default value: ["typo", "words", "proximity", "attribute", "wordsPosition", "exactness"].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_ranking_rules().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_ranking_rules(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/ranking-rules", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [attributes for faceting](https://docs.meilisearch.com/reference/features/faceted_search.html) of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_attributes_for_faceting().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_attributes_for_faceting(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/attributes-for-faceting", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_distinct_attribute().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_distinct_attribute(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/distinct-attribute", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the index (enable all attributes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_searchable_attributes().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_searchable_attributes(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/searchable-attributes", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the index (enable all attributes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_displayed_attributes().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_displayed_attributes(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/displayed-attributes", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the index (no attribute can be used in filters anymore).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_filterable_attributes().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_filterable_attributes(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/filterable-attributes", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the index (search results can't be sorted anymore).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_sortable_attributes().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_sortable_attributes(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/sortable-attributes", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset the [typo tolerance settings](https://docs.meilisearch.com/learn/configuration/typo_tolerance.html) of the index (typos are tolerated again with the default word sizes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_typo_tolerance().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_typo_tolerance(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/typo-tolerance", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset the [faceting settings](https://docs.meilisearch.com/reference/api/settings.html#faceting) of the index (at most 100 values are returned for each facet, sorted alphanumerically).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_faceting().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_faceting(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/faceting", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset the [pagination settings](https://docs.meilisearch.com/reference/api/settings.html#pagination) of the index (searches return at most 1000 hits).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_pagination().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_pagination(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/pagination", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [dictionary](https://docs.meilisearch.com/reference/api/settings.html#dictionary) of the index (no custom word is defined anymore).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_dictionary().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_dictionary(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/dictionary", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [separator tokens](https://docs.meilisearch.com/reference/api/settings.html#separator-tokens) of the index (only the default separators are used).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_separator_tokens().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_separator_tokens(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/separator-tokens", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset [non-separator tokens](https://docs.meilisearch.com/reference/api/settings.html#non-separator-tokens) of the index (all the default separators are used).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_non_separator_tokens().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_non_separator_tokens(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/non-separator-tokens", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset the [proximity precision](https://docs.meilisearch.com/reference/api/settings.html#proximity-precision) of the index (back to `byWord`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_proximity_precision().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_proximity_precision(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/proximity-precision", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset the [search cutoff](https://docs.meilisearch.com/reference/api/settings.html#search-cutoff) of the index (back to 1500ms).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_search_cutoff_ms().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_search_cutoff_ms(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/search-cutoff-ms", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+
+    /// Reset the [embedders](https://docs.meilisearch.com/reference/api/settings.html#embedders) of the index (vector search is disabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, document::*, settings::Settings};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movie_index = client.get_or_create("movies").await.unwrap();
+    ///
+    /// let progress = movie_index.reset_embedders().await.unwrap();
+    /// # progress.wait().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_embedders(&self) -> Result<TaskHandle, Error> {
+        Ok(request::<(), TaskInfo>(
+            &format!("{}/indexes/{}/settings/embedders", self.host, self.uid),
+            &self.config,
+            Method::Delete,
+            202,
+        ).await?
+        .into_task_handle(&self.host, &self.config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::*, settings::*, tasks::TaskStatus};
+    use futures_await_test::async_test;
+
+    #[async_test]
+    async fn test_apply_settings_file() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_apply_settings_file").await.unwrap();
+        let index = client.get_or_create("test_apply_settings_file").await.unwrap();
+
+        let settings = Settings::new()
+            .with_stop_words(["a", "the"])
+            .with_sortable_attributes(["price"]);
+        let path = std::env::temp_dir().join("meilisearch_sdk_test_apply_settings_file.json");
+        settings.to_writer(std::fs::File::create(&path).unwrap()).unwrap();
+
+        let progress = index.apply_settings_file(&path).await.unwrap();
+        progress.wait().await.unwrap();
+        let fetched = index.get_settings().await.unwrap();
+        assert!(fetched.diff(&settings).is_empty());
+
+        std::fs::remove_file(&path).unwrap();
+        client.delete_index("test_apply_settings_file").await.unwrap();
+    }
+
+    #[test]
+    fn test_settings_diff() {
+        let mut synonyms = HashMap::new();
+        synonyms.insert("logan".to_string(), vec!["wolverine".to_string(), "xmen".to_string()]);
+        let current = Settings::new()
+            .with_synonyms(synonyms.clone())
+            .with_stop_words(["the", "a"])
+            .with_ranking_rules(["words", "typo"])
+            .with_filterable_attributes(["genre"]);
+
+        synonyms.insert("logan".to_string(), vec!["xmen".to_string(), "wolverine".to_string()]);
+        let wanted = Settings::new()
+            .with_synonyms(synonyms)
+            .with_stop_words(["a", "the"])
+            .with_ranking_rules(["typo", "words"])
+            .with_sortable_attributes(["price"]);
+
+        let diff = current.diff(&wanted);
+        assert_eq!(diff.changed, vec!["rankingRules", "sortableAttributes"]);
+        assert_eq!(diff.changes, Settings::new().with_ranking_rules(["typo", "words"]).with_sortable_attributes(["price"]));
+        assert!(current.diff(&current).is_empty());
+        assert!(current.diff(&Settings::new()).is_empty());
+    }
+
+    #[test]
+    fn test_settings_diff_partial_objects() {
+        // The server returns every field of the object settings
+        let current: Settings = serde_json::from_value(serde_json::json!({
+            "typoTolerance": {
+                "enabled": true,
+                "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 },
+                "disableOnWords": [],
+                "disableOnAttributes": []
+            },
+            "faceting": { "maxValuesPerFacet": 100, "sortFacetValuesBy": { "*": "alpha" } },
+            "pagination": { "maxTotalHits": 1000 }
+        }))
+        .unwrap();
+
+        let wanted = Settings::new()
+            .with_typo_tolerance(TypoToleranceSettings::new().with_min_word_size_for_typos(5, 9))
+            .with_faceting(FacetingSettings::new().with_max_values_per_facet(100))
+            .with_pagination(PaginationSettings { max_total_hits: Some(1000) });
+        assert!(current.diff(&wanted).is_empty());
+
+        let wanted = Settings::new()
+            .with_typo_tolerance(TypoToleranceSettings::new().with_enabled(true).with_disable_on_words(["the"]))
+            .with_faceting(FacetingSettings::new().with_sort_facet_values_by("genre", FacetSortValue::Count));
+        assert_eq!(current.diff(&wanted).changed, vec!["typoTolerance", "faceting"]);
+    }
+
+    #[test]
+    fn test_partial_settings_serialization() {
+        let settings = Settings::new()
+            .with_stop_words(["a", "the"])
+            .with_ranking_rules(RankingRule::DEFAULT)
+            .with_typo_tolerance(TypoToleranceSettings::new().with_enabled(false));
+
+        assert_eq!(serde_json::to_value(&settings).unwrap(), serde_json::json!({
+            "stopWords": ["a", "the"],
+            "rankingRules": ["words", "typo", "proximity", "attribute", "sort", "exactness"],
+            "typoTolerance": { "enabled": false },
+        }));
+        assert_eq!(serde_json::to_value(&Settings::new()).unwrap(), serde_json::json!({}));
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_settings() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_settings").await.unwrap();
+        let index = client.get_or_create("test_settings").await.unwrap();
+
+        let settings = Settings::new()
+            .with_stop_words(["a", "the", "of"])
+            .with_distinct_attribute("url")
+            .with_filterable_attributes(["genre", "price"])
+            .with_sortable_attributes(["price"]);
+        index.set_settings(&settings).await.unwrap().wait().await.unwrap();
+
+        let fetched = index.get_settings().await.unwrap();
+        let mut stop_words = fetched.stop_words.unwrap();
+        stop_words.sort();
+        assert_eq!(stop_words, vec!["a", "of", "the"]);
+        assert_eq!(fetched.distinct_attribute.unwrap(), "url");
+        let mut filterable_attributes = fetched.filterable_attributes.unwrap();
+        filterable_attributes.sort();
+        assert_eq!(filterable_attributes, vec!["genre", "price"]);
+        assert_eq!(fetched.sortable_attributes.unwrap(), vec!["price"]);
+
+        index.reset_settings().await.unwrap().wait().await.unwrap();
+
+        let fetched = index.get_settings().await.unwrap();
+        assert!(fetched.stop_words.unwrap().is_empty());
+        assert!(fetched.distinct_attribute.is_none());
+        assert!(fetched.filterable_attributes.unwrap().is_empty());
+        assert!(fetched.sortable_attributes.unwrap().is_empty());
+
+        client.delete_index("test_settings").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_synonyms() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_synonyms").await.unwrap();
+        let index = client.get_or_create("test_synonyms").await.unwrap();
+
+        let mut synonyms = HashMap::new();
+        synonyms.insert(String::from("wolverine"), vec![String::from("logan")]);
+        synonyms.insert(String::from("logan"), vec![String::from("wolverine")]);
+        index.set_synonyms(&synonyms).await.unwrap().wait().await.unwrap();
+        assert_eq!(index.get_synonyms().await.unwrap(), synonyms);
+
+        index.reset_synonyms().await.unwrap().wait().await.unwrap();
+        assert!(index.get_synonyms().await.unwrap().is_empty());
+
+        client.delete_index("test_synonyms").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_stop_words() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_stop_words").await.unwrap();
+        let index = client.get_or_create("test_stop_words").await.unwrap();
+
+        let progress = index.set_stop_words(&["the", "of", "to"][..]).await.unwrap();
+        let task = progress.wait().await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+        let mut stop_words = index.get_stop_words().await.unwrap();
+        stop_words.sort();
+        assert_eq!(stop_words, vec!["of", "the", "to"]);
+
+        let progress = index.reset_stop_words().await.unwrap();
+        progress.wait().await.unwrap();
+        assert!(index.get_stop_words().await.unwrap().is_empty());
+
+        client.delete_index("test_stop_words").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_ranking_rules() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_ranking_rules").await.unwrap();
+        let index = client.get_or_create("test_ranking_rules").await.unwrap();
+
+        let mut ranking_rules = RankingRule::DEFAULT.to_vec();
+        ranking_rules.push(RankingRule::Desc("release_date".to_string()));
+        let progress = index.set_ranking_rules(ranking_rules).await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(
+            index.get_ranking_rules().await.unwrap(),
+            vec!["words", "typo", "proximity", "attribute", "sort", "exactness", "release_date:desc"]
+        );
+
+        let progress = index.reset_ranking_rules().await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_ranking_rules().await.unwrap(), RankingRule::DEFAULT.convert());
+
+        client.delete_index("test_ranking_rules").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_distinct_attribute() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_distinct_attribute").await.unwrap();
+        let index = client.get_or_create("test_distinct_attribute").await.unwrap();
+
+        let progress = index.set_distinct_attribute("product_id").await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_distinct_attribute().await.unwrap(), Some("product_id".to_string()));
+
+        let progress = index.reset_distinct_attribute().await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_distinct_attribute().await.unwrap(), None);
+
+        client.delete_index("test_distinct_attribute").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_searchable_attributes() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_searchable_attributes").await.unwrap();
+        let index = client.get_or_create("test_searchable_attributes").await.unwrap();
+
+        let progress = index.set_searchable_attributes(["title", "overview", "genres"]).await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_searchable_attributes().await.unwrap(), vec!["title", "overview", "genres"]);
+
+        let progress = index.reset_searchable_attributes().await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_searchable_attributes().await.unwrap(), vec!["*"]);
+
+        client.delete_index("test_searchable_attributes").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_displayed_attributes() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_displayed_attributes").await.unwrap();
+        let index = client.get_or_create("test_displayed_attributes").await.unwrap();
+
+        let progress = index.set_displayed_attributes(["id", "title"]).await.unwrap();
+        progress.wait().await.unwrap();
+        let mut displayed_attributes = index.get_displayed_attributes().await.unwrap();
+        displayed_attributes.sort();
+        assert_eq!(displayed_attributes, vec!["id", "title"]);
+
+        let progress = index.reset_displayed_attributes().await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_displayed_attributes().await.unwrap(), vec!["*"]);
+
+        client.delete_index("test_displayed_attributes").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_filterable_attributes() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_filterable_attributes").await.unwrap();
+        let index = client.get_or_create("test_filterable_attributes").await.unwrap();
+
+        let progress = index.set_filterable_attributes(["genres", "director"]).await.unwrap();
+        progress.wait().await.unwrap();
+        let mut filterable_attributes = index.get_filterable_attributes().await.unwrap();
+        filterable_attributes.sort();
+        assert_eq!(filterable_attributes, vec!["director", "genres"]);
+
+        let progress = index.reset_filterable_attributes().await.unwrap();
+        progress.wait().await.unwrap();
+        assert!(index.get_filterable_attributes().await.unwrap().is_empty());
+
+        client.delete_index("test_filterable_attributes").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_sortable_attributes() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_sortable_attributes").await.unwrap();
+        let index = client.get_or_create("test_sortable_attributes").await.unwrap();
+
+        let progress = index.set_sortable_attributes(["price", "release_date"]).await.unwrap();
+        progress.wait().await.unwrap();
+        let mut sortable_attributes = index.get_sortable_attributes().await.unwrap();
+        sortable_attributes.sort();
+        assert_eq!(sortable_attributes, vec!["price", "release_date"]);
+
+        let progress = index.reset_sortable_attributes().await.unwrap();
+        progress.wait().await.unwrap();
+        assert!(index.get_sortable_attributes().await.unwrap().is_empty());
+
+        client.delete_index("test_sortable_attributes").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_typo_tolerance() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_typo_tolerance").await.unwrap();
+        let index = client.get_or_create("test_typo_tolerance").await.unwrap();
+
+        let typo_tolerance = TypoToleranceSettings::new()
+            .with_min_word_size_for_typos(4, 8)
+            .with_disable_on_attributes(["sku"]);
+        let progress = index.set_typo_tolerance(&typo_tolerance).await.unwrap();
+        progress.wait().await.unwrap();
+        let fetched = index.get_typo_tolerance().await.unwrap();
+        assert_eq!(fetched.enabled, Some(true));
+        assert_eq!(fetched.min_word_size_for_typos, typo_tolerance.min_word_size_for_typos);
+        assert_eq!(fetched.disable_on_attributes.unwrap(), vec!["sku"]);
+
+        let progress = index.reset_typo_tolerance().await.unwrap();
+        progress.wait().await.unwrap();
+        let fetched = index.get_typo_tolerance().await.unwrap();
+        assert_eq!(fetched.min_word_size_for_typos, Some(MinWordSizeForTypos { one_typo: Some(5), two_typos: Some(9) }));
+        assert!(fetched.disable_on_attributes.unwrap().is_empty());
+
+        client.delete_index("test_typo_tolerance").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_faceting() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_faceting").await.unwrap();
+        let index = client.get_or_create("test_faceting").await.unwrap();
+
+        let faceting = FacetingSettings::new()
+            .with_max_values_per_facet(500)
+            .with_sort_facet_values_by("*", FacetSortValue::Alpha)
+            .with_sort_facet_values_by("genres", FacetSortValue::Count);
+        let progress = index.set_faceting(&faceting).await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_faceting().await.unwrap(), faceting);
+
+        let progress = index.reset_faceting().await.unwrap();
+        progress.wait().await.unwrap();
+        let fetched = index.get_faceting().await.unwrap();
+        assert_eq!(fetched.max_values_per_facet, Some(100));
+        assert_eq!(fetched.sort_facet_values_by.unwrap()["*"], FacetSortValue::Alpha);
+
+        client.delete_index("test_faceting").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_pagination() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_pagination_settings").await.unwrap();
+        let index = client.get_or_create("test_pagination_settings").await.unwrap();
+
+        let pagination = PaginationSettings { max_total_hits: Some(10_000) };
+        let progress = index.set_pagination(&pagination).await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_pagination().await.unwrap(), pagination);
+
+        let progress = index.reset_pagination().await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_pagination().await.unwrap().max_total_hits, Some(1000));
+
+        client.delete_index("test_pagination_settings").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_dictionary() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_dictionary").await.unwrap();
+        let index = client.get_or_create("test_dictionary").await.unwrap();
+
+        let progress = index.set_dictionary(["J. R. R.", "W. E. B."]).await.unwrap();
+        progress.wait().await.unwrap();
+        let mut dictionary = index.get_dictionary().await.unwrap();
+        dictionary.sort();
+        assert_eq!(dictionary, vec!["J. R. R.", "W. E. B."]);
+
+        let progress = index.reset_dictionary().await.unwrap();
+        progress.wait().await.unwrap();
+        assert!(index.get_dictionary().await.unwrap().is_empty());
+
+        client.delete_index("test_dictionary").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_separator_tokens() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_separator_tokens").await.unwrap();
+        let index = client.get_or_create("test_separator_tokens").await.unwrap();
+
+        let progress = index.set_separator_tokens(["|", "&hellip;"]).await.unwrap();
+        progress.wait().await.unwrap();
+        let mut separator_tokens = index.get_separator_tokens().await.unwrap();
+        separator_tokens.sort();
+        assert_eq!(separator_tokens, vec!["&hellip;", "|"]);
+
+        let progress = index.reset_separator_tokens().await.unwrap();
+        progress.wait().await.unwrap();
+        assert!(index.get_separator_tokens().await.unwrap().is_empty());
+
+        client.delete_index("test_separator_tokens").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_non_separator_tokens() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_non_separator_tokens").await.unwrap();
+        let index = client.get_or_create("test_non_separator_tokens").await.unwrap();
+
+        let progress = index.set_non_separator_tokens(["#", "-"]).await.unwrap();
+        progress.wait().await.unwrap();
+        let mut non_separator_tokens = index.get_non_separator_tokens().await.unwrap();
+        non_separator_tokens.sort();
+        assert_eq!(non_separator_tokens, vec!["#", "-"]);
+
+        let progress = index.reset_non_separator_tokens().await.unwrap();
+        progress.wait().await.unwrap();
+        assert!(index.get_non_separator_tokens().await.unwrap().is_empty());
+
+        client.delete_index("test_non_separator_tokens").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_proximity_precision() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_proximity_precision").await.unwrap();
+        let index = client.get_or_create("test_proximity_precision").await.unwrap();
+
+        let progress = index.set_proximity_precision(ProximityPrecision::ByAttribute).await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_proximity_precision().await.unwrap(), ProximityPrecision::ByAttribute);
+
+        let progress = index.reset_proximity_precision().await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_proximity_precision().await.unwrap(), ProximityPrecision::ByWord);
+
+        client.delete_index("test_proximity_precision").await.unwrap();
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_search_cutoff_ms() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_search_cutoff_ms").await.unwrap();
+        let index = client.get_or_create("test_search_cutoff_ms").await.unwrap();
+
+        let progress = index.set_search_cutoff_ms(150).await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_search_cutoff_ms().await.unwrap(), Some(150));
+
+        let progress = index.reset_search_cutoff_ms().await.unwrap();
+        progress.wait().await.unwrap();
+        assert_eq!(index.get_search_cutoff_ms().await.unwrap(), None);
+
+        client.delete_index("test_search_cutoff_ms").await.unwrap();
+    }
+
+    #[test]
+    fn test_embedder_serialization() {
+        let embedder = Embedder::OpenAi(OpenAiEmbedder {
+            model: Some("text-embedding-3-small".to_string()),
+            document_template: Some("A movie titled {{doc.title}}".to_string()),
+            ..OpenAiEmbedder::default()
+        });
+        let json = serde_json::json!({
+            "source": "openAi",
+            "model": "text-embedding-3-small",
+            "documentTemplate": "A movie titled {{doc.title}}",
+        });
+        assert_eq!(serde_json::to_value(&embedder).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Embedder>(json).unwrap(), embedder);
+    }
+
+    #[async_test]
+    async fn test_set_get_reset_embedders() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        client.delete_index_if_exists("test_embedders").await.unwrap();
+        let index = client.get_or_create("test_embedders").await.unwrap();
+
+        let mut embedders = HashMap::new();
+        embedders.insert("default".to_string(), Embedder::UserProvided(UserProvidedEmbedder {
+            dimensions: 3,
+            ..UserProvidedEmbedder::default()
+        }));
+        let progress = index.set_embedders(&embedders).await.unwrap();
+        progress.wait().await.unwrap();
+        let fetched = index.get_embedders().await.unwrap();
+        assert!(matches!(fetched["default"], Embedder::UserProvided(UserProvidedEmbedder { dimensions: 3, .. })));
+
+        let progress = index.reset_embedders().await.unwrap();
+        progress.wait().await.unwrap();
+        assert!(index.get_embedders().await.unwrap().is_empty());
+
+        client.delete_index("test_embedders").await.unwrap();
     }
 }
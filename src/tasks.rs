@@ -0,0 +1,914 @@
+use crate::{client::Client, errors::Error, progress::async_sleep, request::*};
+use futures::{future::join_all, stream::{self, Stream}};
+use serde::{Deserialize, Serialize};
+use std::{collections::{HashMap, VecDeque}, time::Duration};
+
+/// The status of a [task](struct.Task.html).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskStatus {
+    /// The task is waiting to be processed
+    Enqueued,
+    /// The task is being processed
+    Processing,
+    /// The task was processed successfully
+    Succeeded,
+    /// The task failed, see [`Task::error`]
+    Failed,
+    /// The task was canceled before being processed
+    Canceled,
+}
+
+impl TaskStatus {
+    /// Whether the task reached a final status, and won't change anymore.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, TaskStatus::Succeeded | TaskStatus::Failed | TaskStatus::Canceled)
+    }
+}
+
+/// The kind of operation performed by a [task](struct.Task.html).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskType {
+    IndexCreation,
+    IndexUpdate,
+    IndexDeletion,
+    IndexSwap,
+    DocumentAdditionOrUpdate,
+    DocumentDeletion,
+    DocumentEdition,
+    SettingsUpdate,
+    DumpCreation,
+    TaskCancelation,
+    TaskDeletion,
+    SnapshotCreation,
+    /// A task type unknown to this version of the SDK
+    #[serde(other)]
+    Unknown,
+}
+
+/// The error that made a [task](struct.Task.html) fail.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TaskError {
+    /// The human readable error message
+    pub message: String,
+    /// The error code, officially documented at https://docs.meilisearch.com/errors
+    pub code: String,
+    /// The type of error
+    #[serde(rename = "type")]
+    pub error_type: String,
+    /// A link to the MeiliSearch documentation for the error
+    pub link: String,
+}
+
+/// An asynchronous operation processed by MeiliSearch.
+///
+/// Dates are [RFC 3339](https://tools.ietf.org/html/rfc3339) strings and the duration is an [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601#Durations) string.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    /// The unique identifier of the task
+    pub uid: u64,
+    /// The index targeted by the task, if any
+    pub index_uid: Option<String>,
+    /// The status of the task
+    pub status: TaskStatus,
+    /// The kind of operation performed by the task
+    #[serde(rename = "type")]
+    pub task_type: TaskType,
+    /// The uid of the task that canceled this one, if any
+    pub canceled_by: Option<u64>,
+    /// Details about the task, which depend on its type
+    pub details: Option<serde_json::Value>,
+    /// Why the task failed, if it did
+    pub error: Option<TaskError>,
+    /// How long the processing of the task took
+    pub duration: Option<String>,
+    /// When the task was enqueued
+    pub enqueued_at: String,
+    /// When the processing of the task started
+    pub started_at: Option<String>,
+    /// When the processing of the task finished
+    pub finished_at: Option<String>,
+}
+
+impl Task {
+    /// Wait until the task reaches a final status, see [`Client::wait_for_task`](../client/struct.Client.html#method.wait_for_task).
+    /// Returns immediately if the task is already finished.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # if let Some(task) = client.get_tasks(&TasksQuery::new(&client)).await.unwrap().results.pop() {
+    /// let task = task.wait_for_completion(&client, None, None).await.unwrap();
+    /// assert!(task.status.is_finished());
+    /// # }
+    /// # });
+    /// ```
+    pub async fn wait_for_completion(
+        self,
+        client: &Client,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        if self.status.is_finished() {
+            Ok(self)
+        } else {
+            client.wait_for_task(self.uid, interval, timeout).await
+        }
+    }
+
+    /// The number of documents received by a document addition or update task, taken from its [details](#structfield.details).
+    pub fn received_documents(&self) -> Option<u64> {
+        self.detail("receivedDocuments")
+    }
+
+    /// The number of documents indexed by a document addition or update task, taken from its [details](#structfield.details).
+    /// It is only known once the task is finished.
+    pub fn indexed_documents(&self) -> Option<u64> {
+        self.detail("indexedDocuments")
+    }
+
+    /// The uid of the dump created by a dump creation task, taken from its [details](#structfield.details).
+    /// It is the name of the dump file, without the `.dump` extension, and is only known once the task succeeded.
+    pub fn dump_uid(&self) -> Option<&str> {
+        self.details.as_ref()?.get("dumpUid")?.as_str()
+    }
+
+    fn detail(&self, name: &str) -> Option<u64> {
+        self.details.as_ref()?.get(name)?.as_u64()
+    }
+}
+
+/// The summary of a [task](struct.Task.html) that was just enqueued, returned by task management methods such as [`Client::cancel_tasks`](../client/struct.Client.html#method.cancel_tasks) and [`Client::delete_tasks`](../client/struct.Client.html#method.delete_tasks).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskInfo {
+    /// The uid of the enqueued task
+    pub task_uid: u64,
+    /// The index targeted by the task, if any
+    pub index_uid: Option<String>,
+    /// The status of the task
+    pub status: TaskStatus,
+    /// The kind of operation performed by the task
+    #[serde(rename = "type")]
+    pub task_type: TaskType,
+    /// When the task was enqueued
+    pub enqueued_at: String,
+}
+
+impl TaskInfo {
+    /// Wait until the task reaches a final status, see [`Client::wait_for_task`](../client/struct.Client.html#method.wait_for_task).
+    pub async fn wait_for_completion(
+        &self,
+        client: &Client,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        client.wait_for_task(self.task_uid, interval, timeout).await
+    }
+}
+
+/// A page of [tasks](struct.Task.html), returned by [`Client::get_tasks`](../client/struct.Client.html#method.get_tasks).
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksResults {
+    /// The tasks, from the most recent to the oldest
+    pub results: Vec<Task>,
+    /// Total number of tasks matching the query
+    pub total: Option<u64>,
+    /// The maximum number of tasks in the page
+    pub limit: u32,
+    /// The uid of the first task of the page
+    pub from: Option<u64>,
+    /// The uid of the first task of the next page, to be used with [`TasksQuery::with_from`]. `None` on the last page.
+    pub next: Option<u64>,
+}
+
+/// Pagination parameters of a [`TasksQuery`].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct TasksPaginationFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
+/// Filters selecting [tasks](struct.Task.html), shared by [`TasksQuery`], [`TasksCancelQuery`] and [`TasksDeletionQuery`].
+///
+/// All the filters are optional, and a task must match all the provided filters to be selected.
+/// The `T` parameter holds the parameters specific to each kind of query.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksFilterQuery<'a, T> {
+    #[serde(skip_serializing)]
+    client: &'a Client,
+    /// Select the tasks with these uids.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uids: Option<&'a [u64]>,
+    /// Select the tasks with these statuses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<&'a [TaskStatus]>,
+    /// Select the tasks with these types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<&'a [TaskType]>,
+    /// Select the tasks targeting these indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_uids: Option<&'a [&'a str]>,
+    /// Select the tasks canceled by these tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canceled_by: Option<&'a [u64]>,
+    /// Select the tasks enqueued before this date (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_enqueued_at: Option<&'a str>,
+    /// Select the tasks enqueued after this date (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_enqueued_at: Option<&'a str>,
+    /// Select the tasks whose processing started before this date (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_started_at: Option<&'a str>,
+    /// Select the tasks whose processing started after this date (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_started_at: Option<&'a str>,
+    /// Select the tasks whose processing finished before this date (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_finished_at: Option<&'a str>,
+    /// Select the tasks whose processing finished after this date (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_finished_at: Option<&'a str>,
+    #[serde(flatten)]
+    pagination: T,
+}
+
+#[allow(missing_docs)]
+impl<'a, T> TasksFilterQuery<'a, T> {
+    pub fn with_uids<'b>(&'b mut self, uids: &'a [u64]) -> &'b mut TasksFilterQuery<'a, T> {
+        self.uids = Some(uids);
+        self
+    }
+    pub fn with_statuses<'b>(&'b mut self, statuses: &'a [TaskStatus]) -> &'b mut TasksFilterQuery<'a, T> {
+        self.statuses = Some(statuses);
+        self
+    }
+    pub fn with_types<'b>(&'b mut self, types: &'a [TaskType]) -> &'b mut TasksFilterQuery<'a, T> {
+        self.types = Some(types);
+        self
+    }
+    pub fn with_index_uids<'b>(&'b mut self, index_uids: &'a [&'a str]) -> &'b mut TasksFilterQuery<'a, T> {
+        self.index_uids = Some(index_uids);
+        self
+    }
+    pub fn with_canceled_by<'b>(&'b mut self, canceled_by: &'a [u64]) -> &'b mut TasksFilterQuery<'a, T> {
+        self.canceled_by = Some(canceled_by);
+        self
+    }
+    pub fn with_before_enqueued_at<'b>(&'b mut self, date: &'a str) -> &'b mut TasksFilterQuery<'a, T> {
+        self.before_enqueued_at = Some(date);
+        self
+    }
+    pub fn with_after_enqueued_at<'b>(&'b mut self, date: &'a str) -> &'b mut TasksFilterQuery<'a, T> {
+        self.after_enqueued_at = Some(date);
+        self
+    }
+    pub fn with_before_started_at<'b>(&'b mut self, date: &'a str) -> &'b mut TasksFilterQuery<'a, T> {
+        self.before_started_at = Some(date);
+        self
+    }
+    pub fn with_after_started_at<'b>(&'b mut self, date: &'a str) -> &'b mut TasksFilterQuery<'a, T> {
+        self.after_started_at = Some(date);
+        self
+    }
+    pub fn with_before_finished_at<'b>(&'b mut self, date: &'a str) -> &'b mut TasksFilterQuery<'a, T> {
+        self.before_finished_at = Some(date);
+        self
+    }
+    pub fn with_after_finished_at<'b>(&'b mut self, date: &'a str) -> &'b mut TasksFilterQuery<'a, T> {
+        self.after_finished_at = Some(date);
+        self
+    }
+
+    fn empty(client: &'a Client, pagination: T) -> TasksFilterQuery<'a, T> {
+        TasksFilterQuery {
+            client,
+            uids: None,
+            statuses: None,
+            types: None,
+            index_uids: None,
+            canceled_by: None,
+            before_enqueued_at: None,
+            after_enqueued_at: None,
+            before_started_at: None,
+            after_started_at: None,
+            before_finished_at: None,
+            after_finished_at: None,
+            pagination,
+        }
+    }
+}
+
+/// A query listing [tasks](struct.Task.html), page by page.
+/// See [`TasksFilterQuery`] for the available filters.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, tasks::*};
+/// # futures::executor::block_on(async move {
+/// let client = Client::new("http://localhost:7700", "masterKey");
+///
+/// let failed_additions = TasksQuery::new(&client)
+///     .with_statuses(&[TaskStatus::Failed])
+///     .with_types(&[TaskType::DocumentAdditionOrUpdate])
+///     .with_limit(10)
+///     .execute()
+///     .await
+///     .unwrap();
+/// # });
+/// ```
+pub type TasksQuery<'a> = TasksFilterQuery<'a, TasksPaginationFilters>;
+
+impl<'a> TasksQuery<'a> {
+    /// Create a query selecting all the tasks.
+    pub fn new(client: &'a Client) -> TasksQuery<'a> {
+        TasksFilterQuery::empty(client, TasksPaginationFilters::default())
+    }
+    /// Start the page at the task with this uid, usually the [`next`](TasksResults::next) uid of the previous page.
+    pub fn with_from<'b>(&'b mut self, from: u64) -> &'b mut TasksQuery<'a> {
+        self.pagination.from = Some(from);
+        self
+    }
+    /// The maximum number of tasks to return (default: 20).
+    pub fn with_limit<'b>(&'b mut self, limit: u32) -> &'b mut TasksQuery<'a> {
+        self.pagination.limit = Some(limit);
+        self
+    }
+    /// Alias for [Client::get_tasks](../client/struct.Client.html#method.get_tasks).
+    pub async fn execute(&self) -> Result<TasksResults, Error> {
+        self.client.get_tasks(self).await
+    }
+}
+
+/// Parameters specific to a [`TasksCancelQuery`]. There are none yet.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct TasksCancelFilters {}
+
+/// A query canceling the enqueued and processing [tasks](struct.Task.html) matching its filters.
+/// See [`TasksFilterQuery`] for the available filters, at least one of them is required.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, tasks::*};
+/// # futures::executor::block_on(async move {
+/// let client = Client::new("http://localhost:7700", "masterKey");
+///
+/// let cancelation = TasksCancelQuery::new(&client)
+///     .with_index_uids(&["movies_cancel_query"])
+///     .with_statuses(&[TaskStatus::Enqueued])
+///     .execute()
+///     .await
+///     .unwrap();
+/// # cancelation.wait_for_completion(&client, None, None).await.unwrap();
+/// # });
+/// ```
+pub type TasksCancelQuery<'a> = TasksFilterQuery<'a, TasksCancelFilters>;
+
+impl<'a> TasksCancelQuery<'a> {
+    /// Create a query without filters. Add at least one filter before executing it.
+    pub fn new(client: &'a Client) -> TasksCancelQuery<'a> {
+        TasksFilterQuery::empty(client, TasksCancelFilters::default())
+    }
+    /// Alias for [Client::cancel_tasks](../client/struct.Client.html#method.cancel_tasks).
+    pub async fn execute(&self) -> Result<TaskInfo, Error> {
+        self.client.cancel_tasks(self).await
+    }
+}
+
+/// Parameters specific to a [`TasksDeletionQuery`]. There are none yet.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct TasksDeletionFilters {}
+
+/// A query deleting the finished [tasks](struct.Task.html) matching its filters from the task history.
+/// See [`TasksFilterQuery`] for the available filters, at least one of them is required.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, tasks::*};
+/// # futures::executor::block_on(async move {
+/// let client = Client::new("http://localhost:7700", "masterKey");
+///
+/// let deletion = TasksDeletionQuery::new(&client)
+///     .with_statuses(&[TaskStatus::Succeeded, TaskStatus::Canceled])
+///     .with_before_finished_at("2021-01-01T00:00:00Z")
+///     .execute()
+///     .await
+///     .unwrap();
+/// # deletion.wait_for_completion(&client, None, None).await.unwrap();
+/// # });
+/// ```
+pub type TasksDeletionQuery<'a> = TasksFilterQuery<'a, TasksDeletionFilters>;
+
+impl<'a> TasksDeletionQuery<'a> {
+    /// Create a query without filters. Add at least one filter before executing it.
+    pub fn new(client: &'a Client) -> TasksDeletionQuery<'a> {
+        TasksFilterQuery::empty(client, TasksDeletionFilters::default())
+    }
+    /// Alias for [Client::delete_tasks](../client/struct.Client.html#method.delete_tasks).
+    pub async fn execute(&self) -> Result<TaskInfo, Error> {
+        self.client.delete_tasks(self).await
+    }
+}
+
+impl Client {
+    /// Get a [task](../tasks/struct.Task.html) from its uid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # let last_task = client.get_tasks(&TasksQuery::new(&client)).await.unwrap().results.remove(0);
+    /// # let task_uid = last_task.uid;
+    /// let task = client.get_task(task_uid).await.unwrap();
+    /// println!("{:?}", task.status);
+    /// # });
+    /// ```
+    pub async fn get_task(&self, uid: u64) -> Result<Task, Error> {
+        request::<(), Task>(
+            &format!("{}/tasks/{}", self.host, uid),
+            &self.config,
+            Method::Get,
+            200,
+        ).await
+    }
+
+    /// Wait until a [task](../tasks/struct.Task.html) reaches a final status (succeeded, failed or canceled), and return it.
+    ///
+    /// The status of the task is first checked after `interval` (default: 50ms), then the delay between checks doubles each time, up to one second (or `interval` if it is longer).
+    /// If the task isn't finished after `timeout` (default: 5 seconds), an [`Error::Timeout`](../errors/enum.Error.html#variant.Timeout) is returned.
+    /// A failed task is not an error: check the [status](../tasks/struct.Task.html#structfield.status) of the returned task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # use std::time::Duration;
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # if let Some(last_task) = client.get_tasks(&TasksQuery::new(&client)).await.unwrap().results.pop() {
+    /// # let task_uid = last_task.uid;
+    /// let task = client
+    ///     .wait_for_task(task_uid, Some(Duration::from_millis(100)), Some(Duration::from_secs(30)))
+    ///     .await
+    ///     .unwrap();
+    /// if task.status == TaskStatus::Failed {
+    ///     println!("{:?}", task.error);
+    /// }
+    /// # }
+    /// # });
+    /// ```
+    pub async fn wait_for_task(
+        &self,
+        task_uid: u64,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        self.wait_for_task_with_progress(task_uid, interval, timeout, |_| ()).await
+    }
+
+    /// Same as [`wait_for_task`](#method.wait_for_task), but `on_progress` is called with the task each time its status is checked, including the last time.
+    ///
+    /// This is useful to report the progress of long operations, for example with [`Task::indexed_documents`](../tasks/struct.Task.html#method.indexed_documents).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # if let Some(last_task) = client.get_tasks(&TasksQuery::new(&client)).await.unwrap().results.pop() {
+    /// # let task_uid = last_task.uid;
+    /// let task = client
+    ///     .wait_for_task_with_progress(task_uid, None, None, |task| {
+    ///         if let (Some(indexed), Some(received)) = (task.indexed_documents(), task.received_documents()) {
+    ///             println!("{}/{} documents indexed", indexed, received);
+    ///         }
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// # });
+    /// ```
+    pub async fn wait_for_task_with_progress<F: FnMut(&Task)>(
+        &self,
+        task_uid: u64,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+        mut on_progress: F,
+    ) -> Result<Task, Error> {
+        let interval = interval.unwrap_or_else(|| Duration::from_millis(50));
+        let timeout = timeout.unwrap_or_else(|| Duration::from_millis(5000));
+        let max_delay = std::cmp::max(interval, Duration::from_secs(1));
+
+        let mut delay = interval;
+        let mut elapsed_time = Duration::new(0, 0);
+        while elapsed_time < timeout {
+            async_sleep(delay).await;
+            elapsed_time += delay;
+
+            let task = self.get_task(task_uid).await?;
+            on_progress(&task);
+            if task.status.is_finished() {
+                return Ok(task);
+            }
+            delay = std::cmp::min(delay * 2, max_delay);
+        }
+
+        Err(Error::Timeout)
+    }
+
+    /// Wait concurrently until several [tasks](../tasks/struct.Task.html) reach a final status.
+    ///
+    /// Each task is polled as in [`wait_for_task`](#method.wait_for_task), with the same `interval` and `timeout`.
+    /// The results are returned in the order of `task_uids`, so that the failure of one task doesn't hide the others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// # let task_uids: Vec<u64> = client.get_tasks(&TasksQuery::new(&client)).await.unwrap().results.iter().map(|task| task.uid).collect();
+    /// for result in client.wait_for_tasks(&task_uids, None, None).await {
+    ///     match result {
+    ///         Ok(task) => println!("task {} is {:?}", task.uid, task.status),
+    ///         Err(error) => println!("could not wait for a task: {}", error),
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub async fn wait_for_tasks(
+        &self,
+        task_uids: &[u64],
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Vec<Result<Task, Error>> {
+        join_all(task_uids.iter().map(|task_uid| self.wait_for_task(*task_uid, interval, timeout))).await
+    }
+
+    /// Get the [tasks](../tasks/struct.Task.html) matching a [query](../tasks/type.TasksQuery.html), from the most recent to the oldest.
+    ///
+    /// Results are paginated: use the [`next`](../tasks/struct.TasksResults.html#structfield.next) uid of the results as the `from` parameter of the next query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    ///
+    /// let mut query = TasksQuery::new(&client);
+    /// query.with_index_uids(&["movies"]).with_limit(50);
+    /// let page = client.get_tasks(&query).await.unwrap();
+    /// if let Some(next) = page.next {
+    ///     let next_page = client.get_tasks(query.with_from(next)).await.unwrap();
+    /// }
+    /// # });
+    /// ```
+    pub async fn get_tasks(&self, query: &TasksQuery<'_>) -> Result<TasksResults, Error> {
+        let parameters = query_string(&serde_json::to_value(query).unwrap());
+        request::<(), TasksResults>(
+            &format!("{}/tasks?{}", self.host, parameters),
+            &self.config,
+            Method::Get,
+            200,
+        ).await
+    }
+
+    /// Watch the [tasks](../tasks/struct.Task.html) matching a [query](../tasks/type.TasksQuery.html), and yield them each time their status changes.
+    ///
+    /// The first page of the query is fetched every `interval`, so the query [limit](../tasks/type.TasksQuery.html#method.with_limit) should be large enough to cover the tasks created between two polls.
+    /// The tasks of the first poll are all yielded, with their current status; after that, only the tasks that are new or whose status changed are.
+    /// Errors are yielded without ending the stream, so that a temporary network failure doesn't stop the watch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # use futures::StreamExt;
+    /// # use std::time::Duration;
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    ///
+    /// let mut query = TasksQuery::new(&client);
+    /// query.with_statuses(&[TaskStatus::Failed]).with_limit(100);
+    /// let mut failures = Box::pin(client.watch_tasks(&query, Duration::from_secs(1)));
+    /// while let Some(task) = failures.next().await {
+    ///     let task = task.unwrap();
+    ///     println!("task {} failed: {:?}", task.uid, task.error);
+    /// }
+    /// # });
+    /// ```
+    pub fn watch_tasks<'a>(&'a self, query: &'a TasksQuery<'a>, interval: Duration) -> impl Stream<Item = Result<Task, Error>> + 'a {
+        let state = (HashMap::<u64, TaskStatus>::new(), VecDeque::<Task>::new(), true);
+        stream::unfold(state, move |(mut seen, mut pending, mut first_poll)| async move {
+            loop {
+                if let Some(task) = pending.pop_front() {
+                    return Some((Ok(task), (seen, pending, first_poll)));
+                }
+
+                if !first_poll {
+                    async_sleep(interval).await;
+                }
+                first_poll = false;
+
+                match self.get_tasks(query).await {
+                    Ok(page) => {
+                        // Oldest tasks first, so that the transitions are yielded in order
+                        for task in page.results.into_iter().rev() {
+                            if seen.insert(task.uid, task.status) != Some(task.status) {
+                                pending.push_back(task);
+                            }
+                        }
+                    }
+                    Err(error) => return Some((Err(error), (seen, pending, first_poll))),
+                }
+            }
+        })
+    }
+
+    /// Cancel the enqueued and processing [tasks](../tasks/struct.Task.html) matching a [query](../tasks/type.TasksCancelQuery.html).
+    ///
+    /// The cancelation itself is a task: the returned [`TaskInfo`](../tasks/struct.TaskInfo.html) can be used to wait for it.
+    /// MeiliSearch refuses to cancel all the tasks implicitly, so the query must have at least one filter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    ///
+    /// let mut query = TasksCancelQuery::new(&client);
+    /// query.with_types(&[TaskType::DocumentAdditionOrUpdate]);
+    /// let cancelation = client.cancel_tasks(&query).await.unwrap();
+    /// let task = cancelation.wait_for_completion(&client, None, None).await.unwrap();
+    /// assert_eq!(task.task_type, TaskType::TaskCancelation);
+    /// # });
+    /// ```
+    pub async fn cancel_tasks(&self, query: &TasksCancelQuery<'_>) -> Result<TaskInfo, Error> {
+        let parameters = query_string(&serde_json::to_value(query).unwrap());
+        request::<(), TaskInfo>(
+            &format!("{}/tasks/cancel?{}", self.host, parameters),
+            &self.config,
+            Method::Post(()),
+            200,
+        ).await
+    }
+
+    /// Delete the finished [tasks](../tasks/struct.Task.html) matching a [query](../tasks/type.TasksDeletionQuery.html) from the task history.
+    ///
+    /// Enqueued and processing tasks are never deleted: [cancel](#method.cancel_tasks) them first.
+    /// The deletion itself is a task: the returned [`TaskInfo`](../tasks/struct.TaskInfo.html) can be used to wait for it.
+    /// MeiliSearch refuses to delete all the tasks implicitly, so the query must have at least one filter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    ///
+    /// let mut query = TasksDeletionQuery::new(&client);
+    /// query.with_index_uids(&["movies"]).with_statuses(&[TaskStatus::Failed]);
+    /// let deletion = client.delete_tasks(&query).await.unwrap();
+    /// let task = deletion.wait_for_completion(&client, None, None).await.unwrap();
+    /// assert_eq!(task.task_type, TaskType::TaskDeletion);
+    /// # });
+    /// ```
+    pub async fn delete_tasks(&self, query: &TasksDeletionQuery<'_>) -> Result<TaskInfo, Error> {
+        let parameters = query_string(&serde_json::to_value(query).unwrap());
+        request::<(), TaskInfo>(
+            &format!("{}/tasks?{}", self.host, parameters),
+            &self.config,
+            Method::Delete,
+            200,
+        ).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::*, tasks::*};
+    use futures_await_test::async_test;
+
+    #[test]
+    fn test_tasks_query_string() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let mut query = TasksQuery::new(&client);
+        query
+            .with_statuses(&[TaskStatus::Enqueued, TaskStatus::Processing])
+            .with_types(&[TaskType::DocumentAdditionOrUpdate])
+            .with_index_uids(&["movies", "books"])
+            .with_after_enqueued_at("2021-12-03T23:00:00Z")
+            .with_from(42)
+            .with_limit(10);
+        assert_eq!(
+            query_string(&serde_json::to_value(&query).unwrap()),
+            "afterEnqueuedAt=2021-12-03T23%3A00%3A00Z&from=42&indexUids=movies%2Cbooks&limit=10&statuses=enqueued%2Cprocessing&types=documentAdditionOrUpdate"
+        );
+    }
+
+    #[test]
+    fn test_cancel_query_string() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let mut query = TasksCancelQuery::new(&client);
+        query.with_uids(&[1, 2, 3]).with_statuses(&[TaskStatus::Enqueued]);
+        assert_eq!(
+            query_string(&serde_json::to_value(&query).unwrap()),
+            "statuses=enqueued&uids=1%2C2%2C3"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_task() {
+        let task: Task = serde_json::from_str(r#"{
+            "uid": 4,
+            "indexUid": "movies",
+            "status": "failed",
+            "type": "documentAdditionOrUpdate",
+            "canceledBy": null,
+            "details": { "receivedDocuments": 67493, "indexedDocuments": 0 },
+            "error": {
+                "message": "Document does not have a `:primaryKey` attribute: `:documentRepresentation`.",
+                "code": "missing_document_id",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#missing_document_id"
+            },
+            "duration": "PT1S",
+            "enqueuedAt": "2021-08-10T14:29:17.000000Z",
+            "startedAt": "2021-08-10T14:29:18.000000Z",
+            "finishedAt": "2021-08-10T14:29:19.000000Z"
+        }"#).unwrap();
+        assert_eq!(task.status, TaskStatus::Failed);
+        assert!(task.status.is_finished());
+        assert_eq!(task.task_type, TaskType::DocumentAdditionOrUpdate);
+        assert_eq!(task.received_documents(), Some(67493));
+        assert_eq!(task.indexed_documents(), Some(0));
+        assert_eq!(task.error.unwrap().code, "missing_document_id");
+
+        let task: Task = serde_json::from_str(r#"{
+            "uid": 5,
+            "indexUid": null,
+            "status": "enqueued",
+            "type": "someFutureTaskType",
+            "enqueuedAt": "2021-08-10T14:29:17.000000Z"
+        }"#).unwrap();
+        assert_eq!(task.task_type, TaskType::Unknown);
+        assert!(!task.status.is_finished());
+    }
+
+    #[async_test]
+    async fn test_wait_for_task() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let page = TasksQuery::new(&client).with_limit(1).execute().await.unwrap();
+
+        if let Some(task) = page.results.into_iter().next() {
+            let task = client.wait_for_task(task.uid, None, Some(std::time::Duration::from_secs(30))).await.unwrap();
+            assert!(task.status.is_finished());
+            let uid = task.uid;
+            assert_eq!(task.wait_for_completion(&client, None, None).await.unwrap().uid, uid);
+        }
+
+        assert!(matches!(
+            client.wait_for_task(u32::MAX as u64, None, Some(std::time::Duration::from_millis(100))).await,
+            Err(Error::Meilisearch(_))
+        ));
+    }
+
+    #[async_test]
+    async fn test_get_tasks() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let mut query = TasksQuery::new(&client);
+        query.with_limit(1);
+        let page = query.execute().await.unwrap();
+        assert!(page.results.len() <= 1);
+        assert_eq!(page.limit, 1);
+
+        if let Some(task) = page.results.first() {
+            assert_eq!(&client.get_task(task.uid).await.unwrap(), task);
+        }
+    }
+
+    #[async_test]
+    async fn test_cancel_tasks() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let cancelation = TasksCancelQuery::new(&client)
+            .with_index_uids(&["test_cancel_tasks"])
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(cancelation.task_type, TaskType::TaskCancelation);
+        assert_eq!(cancelation.index_uid, None);
+
+        let task = cancelation.wait_for_completion(&client, None, Some(std::time::Duration::from_secs(30))).await.unwrap();
+        assert_eq!(task.uid, cancelation.task_uid);
+        assert_eq!(task.status, TaskStatus::Succeeded);
+    }
+
+    #[async_test]
+    async fn test_delete_tasks() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let cancelation = TasksCancelQuery::new(&client)
+            .with_index_uids(&["test_delete_tasks"])
+            .execute()
+            .await
+            .unwrap();
+        cancelation.wait_for_completion(&client, None, Some(std::time::Duration::from_secs(30))).await.unwrap();
+
+        let deletion = TasksDeletionQuery::new(&client)
+            .with_uids(&[cancelation.task_uid])
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(deletion.task_type, TaskType::TaskDeletion);
+        let task = deletion.wait_for_completion(&client, None, Some(std::time::Duration::from_secs(30))).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Succeeded);
+
+        assert!(matches!(
+            client.get_task(cancelation.task_uid).await,
+            Err(Error::Meilisearch(_))
+        ));
+    }
+
+    #[async_test]
+    async fn test_wait_for_tasks() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let page = TasksQuery::new(&client).with_limit(5).execute().await.unwrap();
+        let mut task_uids: Vec<u64> = page.results.iter().map(|task| task.uid).collect();
+        task_uids.push(u32::MAX as u64);
+
+        let results = client.wait_for_tasks(&task_uids, None, Some(std::time::Duration::from_secs(30))).await;
+        assert_eq!(results.len(), task_uids.len());
+        for (task_uid, result) in task_uids[..task_uids.len() - 1].iter().zip(&results) {
+            let task = result.as_ref().unwrap();
+            assert_eq!(task.uid, *task_uid);
+            assert!(task.status.is_finished());
+        }
+        assert!(matches!(results.last(), Some(Err(Error::Meilisearch(_)))));
+    }
+
+    #[async_test]
+    async fn test_watch_tasks() {
+        use futures::StreamExt;
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let cancelation = TasksCancelQuery::new(&client)
+            .with_index_uids(&["test_watch_tasks"])
+            .execute()
+            .await
+            .unwrap();
+
+        let uids = [cancelation.task_uid];
+        let mut query = TasksQuery::new(&client);
+        query.with_uids(&uids);
+        let mut updates = Box::pin(client.watch_tasks(&query, std::time::Duration::from_millis(50)));
+
+        let mut last_status = None;
+        while let Some(task) = updates.next().await {
+            let task = task.unwrap();
+            assert_eq!(task.uid, cancelation.task_uid);
+            assert_ne!(Some(task.status), last_status);
+            last_status = Some(task.status);
+            if task.status.is_finished() {
+                break;
+            }
+        }
+        assert_eq!(last_status, Some(TaskStatus::Succeeded));
+    }
+
+    #[async_test]
+    async fn test_wait_for_task_with_progress() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let cancelation = TasksCancelQuery::new(&client)
+            .with_index_uids(&["test_wait_for_task_with_progress"])
+            .execute()
+            .await
+            .unwrap();
+
+        let mut statuses = Vec::new();
+        let task = client
+            .wait_for_task_with_progress(cancelation.task_uid, None, Some(std::time::Duration::from_secs(30)), |task| {
+                statuses.push(task.status)
+            })
+            .await
+            .unwrap();
+        assert_eq!(statuses.last(), Some(&task.status));
+        assert!(statuses[..statuses.len() - 1].iter().all(|status| !status.is_finished()));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{client::Client, errors::Error};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TenantTokenClaims<'a> {
+    api_key_uid: &'a str,
+    search_rules: &'a Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exp: Option<u64>,
+}
+
+/// Tenant token related methods.
+impl Client {
+    /// Generate a [tenant token](https://docs.meilisearch.com/learn/security/tenant_tokens.html), to give a frontend restricted search access.
+    ///
+    /// The token is a JWT signed locally with the API key of the client, whose uid must be `api_key_uid`.
+    /// That key must be allowed to search, and the searches made with the token are limited by the `search_rules`:
+    /// either a list of index uids, or an object mapping index uids (or `*`) to search parameters such as a `filter` forced on every search.
+    /// The token can't be used after `expires_at`, nor after the API key is deleted or expired.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// # use serde_json::json;
+    /// # use std::time::{Duration, SystemTime};
+    /// let client = Client::new("http://localhost:7700", "W13pxfXF8fC1sNHvl7l3C8OeQfSe6G6w4ucHsgJuvYUrEhFtOWsfk6ICPv16ZKbE");
+    ///
+    /// let search_rules = json!({ "medical_records": { "filter": "user_id = 1" } });
+    /// let expires_at = SystemTime::now() + Duration::from_secs(3600);
+    /// let token = client
+    ///     .generate_tenant_token("85c3c2f9-bdd6-41f1-abd8-11fcf80e0f76", search_rules, Some(expires_at))
+    ///     .unwrap();
+    ///
+    /// // The token can be used by a frontend, as the key of its client
+    /// let frontend_client = Client::new("http://localhost:7700", token);
+    /// ```
+    pub fn generate_tenant_token(
+        &self,
+        api_key_uid: impl AsRef<str>,
+        search_rules: Value,
+        expires_at: Option<SystemTime>,
+    ) -> Result<String, Error> {
+        let claims = TenantTokenClaims {
+            api_key_uid: api_key_uid.as_ref(),
+            search_rules: &search_rules,
+            exp: expires_at.map(|expires_at| {
+                expires_at.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs())
+            }),
+        };
+        let payload = serde_json::to_vec(&claims).map_err(Error::ParseError)?;
+
+        let mut token = base64_url_encode(br#"{"alg":"HS256","typ":"JWT"}"#);
+        token.push('.');
+        token.push_str(&base64_url_encode(&payload));
+        let signature = hmac_sha256(self.config.api_key.as_bytes(), token.as_bytes());
+        token.push('.');
+        token.push_str(&base64_url_encode(&signature));
+        Ok(token)
+    }
+}
+
+/// Encode in the URL-safe base64 alphabet, without padding, as JWTs are.
+fn base64_url_encode(data: &[u8]) -> String {
+    base64::encode_config(data, base64::URL_SAFE_NO_PAD)
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take a key of any size");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_await_test::async_test;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn test_generate_tenant_token() {
+        let client = Client::new("http://localhost:7700", "key");
+        let expires_at = UNIX_EPOCH + Duration::from_secs(2000000000);
+        let token = client
+            .generate_tenant_token("uid", json!({ "*": { "filter": "tenant = 1" } }), Some(expires_at))
+            .unwrap();
+
+        let parts: Vec<&str> = token.split('.').collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9");
+        assert_eq!(
+            parts[1],
+            "eyJhcGlLZXlVaWQiOiJ1aWQiLCJzZWFyY2hSdWxlcyI6eyIqIjp7ImZpbHRlciI6InRlbmFudCA9IDEifX0sImV4cCI6MjAwMDAwMDAwMH0"
+        );
+        assert_eq!(parts[2], "yF95MT4_GGnv-F-vKmAQ2mZtEbfMOmxbVdH_PkzjRf8");
+    }
+
+    #[async_test]
+    async fn test_search_with_tenant_token() {
+        use crate::key::{Action, KeyBuilder};
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.get_or_create("test_search_with_tenant_token").await.unwrap();
+        index.update_documents(&[json!({ "id": 1, "tenant": 1 }), json!({ "id": 2, "tenant": 2 })], Some("id")).await.unwrap().wait().await.unwrap();
+        index.set_filterable_attributes(&["tenant"][..]).await.unwrap().wait().await.unwrap();
+
+        let key = client.create_key(&KeyBuilder::new([Action::Search], ["*"])).await.unwrap();
+        let token = Client::new("http://localhost:7700", &key.key)
+            .generate_tenant_token(&key.uid, json!({ "test_search_with_tenant_token": { "filter": "tenant = 1" } }), None)
+            .unwrap();
+        let tenant_client = Client::new("http://localhost:7700", token);
+        let tenant_index = tenant_client.assume_index("test_search_with_tenant_token");
+        let results = tenant_index.search().execute::<Value>().await.unwrap();
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].result["id"], 1);
+
+        client.delete_key(&key.uid).await.unwrap();
+        client.delete_index("test_search_with_tenant_token").await.unwrap();
+    }
+}